@@ -0,0 +1,32 @@
+//! Instantiates the ciphers on targets with a 16-bit `usize`, such as MSP430 or AVR, so that
+//! building this crate for such a target compiles the generic code for them.
+//!
+//! There is no CI for these targets. Build it manually with a nightly toolchain and the `rust-src`
+//! component:
+//!
+//! ```sh
+//! cargo +nightly build -Z build-std=core --target msp430-none-elf
+//! RUSTFLAGS="-C target-cpu=atmega328p" cargo +nightly build -Z build-std=core --target avr-none
+//! ```
+
+#![no_std]
+
+use rc5::{RC5, RC6};
+
+/// RC5-16/12/8
+pub fn rc5_16_12_8(key: [u8; 8], block: [u8; 4]) -> [u8; 4] {
+    let rc5 = RC5::<16, 12, 8, 2, 4, 26, 4>::new(key);
+    rc5.decrypt(rc5.encrypt(block))
+}
+
+/// RC5-32/12/16
+pub fn rc5_32_12_16(key: [u8; 16], block: [u8; 8]) -> [u8; 8] {
+    let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(key);
+    rc5.decrypt(rc5.encrypt(block))
+}
+
+/// RC6-16/20/8
+pub fn rc6_16_20_8(key: [u8; 8], block: [u8; 8]) -> [u8; 8] {
+    let rc6 = RC6::<16, 20, 8, 2, 8, 44, 4>::new(key);
+    rc6.decrypt(rc6.encrypt(block))
+}
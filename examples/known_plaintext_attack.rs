@@ -0,0 +1,48 @@
+//! Demonstrates recovering a reduced-round RC5-8 cipher from known plaintext.
+//!
+//! Run with `cargo run --example known_plaintext_attack --features attack`.
+
+#[cfg(not(feature = "strict"))]
+use rc5::RC5;
+
+// RC5-8/2/4
+#[cfg(not(feature = "strict"))]
+type Rc5 = RC5<8, 2, 4, 1, 2, 6, 4>;
+
+#[cfg(not(feature = "strict"))]
+fn main() {
+    let secret_key = [0x5A, 0x17, 0xC3, 0x88];
+    let victim = Rc5::new(secret_key);
+
+    // The attacker observes a few plaintext blocks along with their encryptions.
+    let plaintexts = [
+        [0x48, 0x69],
+        [0x52, 0x43],
+        [0x35, 0x21],
+        [0x7E, 0x00],
+        [0xC0, 0xDE],
+    ];
+    let pairs = plaintexts.map(|plaintext| (plaintext, victim.encrypt(plaintext)));
+    for (plaintext, ciphertext) in pairs {
+        println!("known pair: {plaintext:02X?} -> {ciphertext:02X?}");
+    }
+
+    let recovered = Rc5::recover_from_known_plaintext(&pairs).expect("No equivalent key found.");
+
+    // The recovered cipher decrypts messages it has never seen.
+    let secret_message = *b"RC5 is fun!!";
+    for chunk in secret_message.chunks_exact(2) {
+        let ciphertext = victim.encrypt([chunk[0], chunk[1]]);
+        let decrypted = recovered.decrypt(ciphertext);
+        println!(
+            "intercepted {ciphertext:02X?} -> decrypted {:?}",
+            core::str::from_utf8(&decrypted).unwrap()
+        );
+        assert_eq!(decrypted, [chunk[0], chunk[1]]);
+    }
+}
+
+#[cfg(feature = "strict")]
+fn main() {
+    eprintln!("The strict feature rules out the reduced-round cipher attacked by this example.");
+}
@@ -0,0 +1,182 @@
+use crate::{arx::rotation_amount, RC5};
+
+/// Upper bound on the number of distinct rotation amounts (reached for 128-bit words).
+const MAX_ROTATION_AMOUNTS: usize = 128;
+
+/// Rotation amounts observed in a single half-round.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HalfRoundRotations {
+    /// Number of times the half-round rotated by zero bits.
+    pub zero_rotations: u64,
+    /// Whether the rotation amount differed between the analyzed blocks.
+    pub data_dependent: bool,
+    first_amount: Option<usize>,
+}
+
+/// Distribution of the data-dependent rotation amounts observed while encrypting a set of blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RotationStats<const ROUNDS: usize> {
+    num_amounts: usize,
+    histogram: [u64; MAX_ROTATION_AMOUNTS],
+    half_rounds: [[HalfRoundRotations; 2]; ROUNDS],
+    num_blocks: u64,
+}
+
+impl<const ROUNDS: usize> RotationStats<ROUNDS> {
+    fn new(num_amounts: usize) -> Self {
+        Self {
+            num_amounts,
+            histogram: [0; MAX_ROTATION_AMOUNTS],
+            half_rounds: [[HalfRoundRotations::default(); 2]; ROUNDS],
+            num_blocks: 0,
+        }
+    }
+
+    fn record(&mut self, round: usize, half: usize, amount: usize) {
+        self.histogram[amount] += 1;
+
+        let half_round = &mut self.half_rounds[round][half];
+        if amount == 0 {
+            half_round.zero_rotations += 1;
+        }
+        match half_round.first_amount {
+            None => half_round.first_amount = Some(amount),
+            Some(first_amount) if first_amount != amount => half_round.data_dependent = true,
+            Some(_) => {}
+        }
+    }
+
+    /// Number of times each rotation amount occurred across all rounds, indexed by amount.
+    pub fn histogram(&self) -> &[u64] {
+        &self.histogram[..self.num_amounts]
+    }
+
+    /// Rotations observed in the A and B halves of every round.
+    pub fn half_rounds(&self) -> &[[HalfRoundRotations; 2]; ROUNDS] {
+        &self.half_rounds
+    }
+
+    /// Number of analyzed blocks.
+    pub fn num_blocks(&self) -> u64 {
+        self.num_blocks
+    }
+
+    /// Returns whether the rotations follow a degenerate pattern.
+    ///
+    /// The pattern is considered degenerate if, given at least two analyzed blocks, any half-round
+    /// rotated by the same amount for every block or rotated by zero bits for more than half of the
+    /// blocks. Both make the affected rounds effectively linear and are what differential and
+    /// linear attacks on reduced-round RC5 exploit.
+    pub fn is_degenerate(&self) -> bool {
+        self.num_blocks >= 2
+            && self.half_rounds.iter().flatten().any(|half_round| {
+                !half_round.data_dependent || 2 * half_round.zero_rotations > self.num_blocks
+            })
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Encrypts the plaintext blocks recording the data-dependent rotation amounts of every round.
+    ///
+    /// Useful for auditing parameter choices and keys for low-round deployments.
+    pub fn analyze_rotations(
+        &self,
+        plaintexts: impl IntoIterator<Item = [u8; BLOCK_SIZE]>,
+    ) -> RotationStats<ROUNDS> {
+        // Rotation amounts are taken modulo the largest power of two not exceeding the word size.
+        let num_amounts = 1 << WORD_BIT_SIZE.ilog2();
+        let mut stats = RotationStats::new(num_amounts);
+
+        for plaintext in plaintexts {
+            let mut last_b = [0; WORD_SIZE];
+            self.encrypt_traced(plaintext, |round, a, b| {
+                if round > 0 {
+                    // A is rotated by the B of the previous round, B by the freshly computed A.
+                    let a_amount = rotation_amount(last_b) as usize % num_amounts;
+                    let b_amount = rotation_amount(*a) as usize % num_amounts;
+                    stats.record(round - 1, 0, a_amount);
+                    stats.record(round - 1, 1, b_amount);
+                }
+                last_b = *b;
+            });
+            stats.num_blocks += 1;
+        }
+
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn analyze_rotations_rc_32_12_16() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(key);
+
+        let stats = rc5.analyze_rotations((0..64u8).map(|idx| [idx, 0, 0, 0, idx, idx, 0, 0]));
+
+        assert_eq!(stats.num_blocks(), 64);
+        assert_eq!(stats.histogram().len(), 32);
+        assert_eq!(stats.histogram().iter().sum::<u64>(), 64 * 2 * 12);
+        assert!(!stats.is_degenerate());
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn analyze_rotations_rc_24_4_0() {
+        let rc5 = RC5::<24, 4, 0, 3, 6, 10, 1>::new([]);
+
+        let stats = rc5.analyze_rotations((0..16u8).map(|idx| [idx, 0, 0, idx, 0, 0]));
+
+        // Rotation amounts of 24-bit words are taken modulo 16.
+        assert_eq!(stats.histogram().len(), 16);
+        assert_eq!(stats.histogram().iter().sum::<u64>(), 16 * 2 * 4);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn analyze_rotations_degenerate() {
+        // With an all-zero table and a zero B half, the first rotation is always by zero bits.
+        let rc5 = RC5::<8, 2, 0, 1, 2, 6, 1>::from_expanded_key_table([[0x00]; 6]);
+
+        let stats = rc5.analyze_rotations((0..16u8).map(|idx| [idx, 0x00]));
+
+        assert_eq!(stats.half_rounds()[0][0].zero_rotations, 16);
+        assert!(!stats.half_rounds()[0][0].data_dependent);
+        assert!(stats.is_degenerate());
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn analyze_rotations_single_block() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+
+        let stats = rc5.analyze_rotations([[0x00, 0x01]]);
+
+        assert_eq!(stats.num_blocks(), 1);
+        assert!(!stats.is_degenerate());
+    }
+}
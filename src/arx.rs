@@ -0,0 +1,70 @@
+use crate::words::ByteIntegerExt;
+
+/// Splits a block into `WORDS` little-endian words.
+pub(crate) fn split_block<const WORD_SIZE: usize, const BLOCK_SIZE: usize, const WORDS: usize>(
+    block: [u8; BLOCK_SIZE],
+) -> [[u8; WORD_SIZE]; WORDS] {
+    let mut words = [[0; WORD_SIZE]; WORDS];
+
+    for (word, chunk) in words.iter_mut().zip(block.chunks_exact(WORD_SIZE)) {
+        *word = <[u8; WORD_SIZE]>::from_le_slice(chunk);
+    }
+
+    words
+}
+
+/// Joins `WORDS` little-endian words into a block.
+pub(crate) fn join_words<const WORD_SIZE: usize, const BLOCK_SIZE: usize, const WORDS: usize>(
+    words: [[u8; WORD_SIZE]; WORDS],
+) -> [u8; BLOCK_SIZE] {
+    let mut output = [0; BLOCK_SIZE];
+
+    for (chunk, word) in output.chunks_exact_mut(WORD_SIZE).zip(words) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+
+    output
+}
+
+/// Interprets a word as a data-dependent rotation amount.
+pub(crate) fn rotation_amount<const WORD_SIZE: usize>(word: [u8; WORD_SIZE]) -> u128 {
+    u128::from_le_bytes(<[u8; 16]>::from_le_slice(&word))
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    /// Asserts that a block cipher maps the plaintext to the ciphertext and back.
+    pub(crate) fn assert_encrypt_decrypt_roundtrip<const BLOCK_SIZE: usize>(
+        encrypt: impl Fn([u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE],
+        decrypt: impl Fn([u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE],
+        plaintext: [u8; BLOCK_SIZE],
+        ciphertext: [u8; BLOCK_SIZE],
+    ) {
+        let output_ciphertext = encrypt(plaintext);
+        assert_eq!(output_ciphertext, ciphertext);
+
+        let output_plaintext = decrypt(output_ciphertext);
+        assert_eq!(output_plaintext, plaintext);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_join_words() {
+        let block = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
+        let words: [[u8; 3]; 2] = split_block(block);
+        assert_eq!(words, [[0x00, 0x01, 0x02], [0x03, 0x04, 0x05]]);
+        assert_eq!(join_words(words), block);
+    }
+
+    #[test]
+    fn rotation_amount_truncates_wide_words() {
+        let mut word = [0x00; 20];
+        word[0] = 0x07;
+        word[16] = 0xFF;
+        assert_eq!(rotation_amount(word), 7);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::RC5;
+
+/// Maximum number of known plaintext/ciphertext pairs used by the attack.
+const MAX_PAIRS: usize = 8;
+
+/// Plaintext halves along with the halves after the last processed round.
+#[derive(Clone, Copy, Default)]
+struct State {
+    a0: u8,
+    b0: u8,
+    a: u8,
+    b: u8,
+}
+
+impl<
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > RC5<8, ROUNDS, KEY_SIZE, 1, 2, EXPANDED_KEY_TABLE_LEN, KEY_AS_WORDS_LEN>
+{
+    /// Recovers a cipher equivalent to the one that produced the known plaintext/ciphertext pairs.
+    ///
+    /// This is an educational attack against RC5-8 reduced to 1 or 2 rounds. Rather than the key
+    /// itself, it recovers the expanded key table, which is all that is needed to encrypt and
+    /// decrypt. The subkeys of the last round are guessed and peeled off until a single round is
+    /// left, which is solved one half at a time: guessing the subkey added to one half of the
+    /// plaintext determines the subkey added at the end of the round, which is then verified
+    /// against the remaining pairs. For 2 rounds this takes roughly 2^25 operations instead of the
+    /// 2^48 required to brute-force the table.
+    ///
+    /// At most 8 pairs are used. Around 4 pairs are usually enough to determine a unique table.
+    ///
+    /// Panics if `ROUNDS` is greater than 2.
+    pub fn recover_from_known_plaintext(pairs: &[([u8; 2], [u8; 2])]) -> Option<Self> {
+        assert!(ROUNDS <= 2, "Attack only supports up to 2 rounds.");
+
+        let mut states = [State::default(); MAX_PAIRS];
+        let num_pairs = pairs.len().min(MAX_PAIRS);
+        for (state, (plaintext, ciphertext)) in states.iter_mut().zip(pairs) {
+            *state = State {
+                a0: plaintext[0],
+                b0: plaintext[1],
+                a: ciphertext[0],
+                b: ciphertext[1],
+            };
+        }
+
+        let mut table = [[0; 1]; EXPANDED_KEY_TABLE_LEN];
+        if recover_rounds(ROUNDS, &states[..num_pairs], &mut table) {
+            Some(Self::from_expanded_key_table(table))
+        } else {
+            None
+        }
+    }
+}
+
+fn recover_rounds(rounds: usize, states: &[State], table: &mut [[u8; 1]]) -> bool {
+    match rounds {
+        0 => recover_key_addition(states, table),
+        1 => recover_single_round(states, table),
+        _ => {
+            let mut peeled = [State::default(); MAX_PAIRS];
+            for s_a in 0..=u8::MAX {
+                for s_b in 0..=u8::MAX {
+                    for (peeled, state) in peeled.iter_mut().zip(states) {
+                        let b = state.b.wrapping_sub(s_b).rotate_right(state.a as u32) ^ state.a;
+                        let a = state.a.wrapping_sub(s_a).rotate_right(b as u32) ^ b;
+                        *peeled = State { a, b, ..*state };
+                    }
+                    if recover_rounds(rounds - 1, &peeled[..states.len()], table) {
+                        table[2 * rounds] = [s_a];
+                        table[2 * rounds + 1] = [s_b];
+                        return true;
+                    }
+                }
+            }
+            false
+        }
+    }
+}
+
+fn recover_key_addition(states: &[State], table: &mut [[u8; 1]]) -> bool {
+    let Some(first) = states.first() else {
+        return false;
+    };
+
+    let s0 = first.a.wrapping_sub(first.a0);
+    let s1 = first.b.wrapping_sub(first.b0);
+    if states
+        .iter()
+        .all(|state| state.a0.wrapping_add(s0) == state.a && state.b0.wrapping_add(s1) == state.b)
+    {
+        table[0] = [s0];
+        table[1] = [s1];
+        true
+    } else {
+        false
+    }
+}
+
+fn recover_single_round(states: &[State], table: &mut [[u8; 1]]) -> bool {
+    let Some(first) = states.first() else {
+        return false;
+    };
+
+    // B = ((B0 + S1) ^ A) <<< A + S3, so S1 determines S3.
+    let s3_for = |state: &State, s1: u8| {
+        let b = state.b0.wrapping_add(s1);
+        state
+            .b
+            .wrapping_sub((b ^ state.a).rotate_left(state.a as u32))
+    };
+    // A = ((A0 + S0) ^ (B0 + S1)) <<< (B0 + S1) + S2, so S0 and S1 determine S2.
+    let s2_for = |state: &State, s0: u8, s1: u8| {
+        let a = state.a0.wrapping_add(s0);
+        let b = state.b0.wrapping_add(s1);
+        state.a.wrapping_sub((a ^ b).rotate_left(b as u32))
+    };
+
+    for s1 in 0..=u8::MAX {
+        let s3 = s3_for(first, s1);
+        if !states.iter().all(|state| s3_for(state, s1) == s3) {
+            continue;
+        }
+
+        for s0 in 0..=u8::MAX {
+            let s2 = s2_for(first, s0, s1);
+            if states.iter().all(|state| s2_for(state, s0, s1) == s2) {
+                table[..4].copy_from_slice(&[[s0], [s1], [s2], [s3]]);
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+mod tests {
+    use super::*;
+
+    const PLAINTEXTS: [[u8; 2]; 6] = [
+        [0x00, 0x01],
+        [0x3C, 0xA7],
+        [0x91, 0x5F],
+        [0xE4, 0x12],
+        [0x6B, 0xD8],
+        [0x27, 0x4E],
+    ];
+
+    #[test]
+    fn recover_rc_8_1_4() {
+        assert_recover::<1, 4>([0xDE, 0xAD, 0xBE, 0xEF]);
+    }
+
+    #[test]
+    fn recover_rc_8_2_4() {
+        assert_recover::<2, 6>([0x00, 0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn recover_not_found() {
+        let pairs = [([0x00, 0x00], [0x00, 0x00]), ([0x00, 0x00], [0x00, 0x01])];
+        assert!(RC5::<8, 1, 4, 1, 2, 4, 4>::recover_from_known_plaintext(&pairs).is_none());
+    }
+
+    fn assert_recover<const ROUNDS: usize, const EXPANDED_KEY_TABLE_LEN: usize>(key: [u8; 4]) {
+        let rc5 = RC5::<8, ROUNDS, 4, 1, 2, EXPANDED_KEY_TABLE_LEN, 4>::new(key);
+
+        let mut pairs = [([0; 2], [0; 2]); 5];
+        for (pair, plaintext) in pairs.iter_mut().zip(PLAINTEXTS) {
+            *pair = (plaintext, rc5.encrypt(plaintext));
+        }
+
+        let recovered =
+            RC5::<8, ROUNDS, 4, 1, 2, EXPANDED_KEY_TABLE_LEN, 4>::recover_from_known_plaintext(
+                &pairs,
+            )
+            .unwrap();
+
+        // The recovered cipher must also match on a block not used during the attack.
+        let plaintext = PLAINTEXTS[5];
+        let ciphertext = rc5.encrypt(plaintext);
+        assert_eq!(recovered.encrypt(plaintext), ciphertext);
+        assert_eq!(recovered.decrypt(ciphertext), plaintext);
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::RC5;
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Encrypts each block in place with the cipher it is paired with, e.g. to process single
+    /// blocks under many distinct keys in one call.
+    ///
+    /// Example usage:
+    /// ```
+    /// use rc5::RC5;
+    ///
+    /// // RC5-32/12/16
+    /// let rc5_a = RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]);
+    /// let rc5_b = RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x01; 16]);
+    ///
+    /// let mut jobs = [(&rc5_a, [0x00; 8]), (&rc5_b, [0x00; 8])];
+    /// RC5::encrypt_batch(&mut jobs);
+    ///
+    /// assert_eq!(jobs[0].1, rc5_a.encrypt([0x00; 8]));
+    /// assert_eq!(jobs[1].1, rc5_b.encrypt([0x00; 8]));
+    /// ```
+    pub fn encrypt_batch(jobs: &mut [(&Self, [u8; BLOCK_SIZE])]) {
+        for (cipher, block) in jobs {
+            *block = cipher.encrypt(*block);
+        }
+    }
+
+    /// Decrypts each block in place with the cipher it is paired with.
+    pub fn decrypt_batch(jobs: &mut [(&Self, [u8; BLOCK_SIZE])]) {
+        for (cipher, block) in jobs {
+            *block = cipher.decrypt(*block);
+        }
+    }
+
+    /// Encrypts each block in place with the cipher it is paired with, in parallel.
+    #[cfg(feature = "rayon")]
+    pub fn par_encrypt_batch(jobs: &mut [(&Self, [u8; BLOCK_SIZE])]) {
+        jobs.par_iter_mut()
+            .for_each(|(cipher, block)| *block = cipher.encrypt(*block));
+    }
+
+    /// Decrypts each block in place with the cipher it is paired with, in parallel.
+    #[cfg(feature = "rayon")]
+    pub fn par_decrypt_batch(jobs: &mut [(&Self, [u8; BLOCK_SIZE])]) {
+        jobs.par_iter_mut()
+            .for_each(|(cipher, block)| *block = cipher.decrypt(*block));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ciphers() -> [RC5<32, 12, 16, 4, 8, 26, 4>; 3] {
+        [
+            RC5::new([0x00; 16]),
+            RC5::new([
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+                0x0E, 0x0F,
+            ]),
+            RC5::new([0xFF; 16]),
+        ]
+    }
+
+    #[test]
+    fn rc_32_12_16_encrypt_decrypt_batch() {
+        let ciphers = ciphers();
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+
+        let mut jobs = [
+            (&ciphers[0], plaintext),
+            (&ciphers[1], plaintext),
+            (&ciphers[2], plaintext),
+            (&ciphers[1], [0x00; 8]),
+        ];
+        RC5::encrypt_batch(&mut jobs);
+        assert_eq!(jobs[1].1, [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E]);
+        for (idx, (cipher, block)) in jobs.iter().take(3).enumerate() {
+            assert_eq!(*block, ciphers[idx].encrypt(plaintext));
+            assert_eq!(cipher.decrypt(*block), plaintext);
+        }
+
+        RC5::decrypt_batch(&mut jobs);
+        assert_eq!(jobs.map(|(_, block)| block)[..3], [plaintext; 3]);
+        assert_eq!(jobs[3].1, [0x00; 8]);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn rc_32_12_16_par_encrypt_decrypt_batch() {
+        let ciphers = ciphers();
+        let mut jobs = [(&ciphers[0], [0x00; 8]); 64];
+        for (idx, job) in jobs.iter_mut().enumerate() {
+            *job = (&ciphers[idx % ciphers.len()], [idx as u8; 8]);
+        }
+
+        let mut expected = jobs;
+        RC5::encrypt_batch(&mut expected);
+        let mut actual = jobs;
+        RC5::par_encrypt_batch(&mut actual);
+        assert_eq!(
+            actual.map(|(_, block)| block),
+            expected.map(|(_, block)| block)
+        );
+
+        RC5::par_decrypt_batch(&mut actual);
+        assert_eq!(actual.map(|(_, block)| block), jobs.map(|(_, block)| block));
+    }
+}
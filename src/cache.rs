@@ -0,0 +1,335 @@
+use crate::{wipe::wipe, words::ByteIntegerExt, RC5};
+
+/// Hit and miss counters of a [`KeyScheduleCache`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of lookups that found an expanded key table in the cache.
+    pub hits: u64,
+    /// Number of lookups that had to expand the key.
+    pub misses: u64,
+}
+
+/// Caches up to `CAPACITY` ciphers by key, evicting the least recently used one when full.
+///
+/// Useful when the same keys are used over and over, e.g. for repeated connections using the same
+/// pre-shared key, to skip the key expansion. The cache is stored inline and does not allocate.
+/// Keys are compared in constant time, but whether a lookup hits the cache is observable through
+/// timing. The keys and expanded key tables of evicted and cleared entries are wiped, as are all
+/// entries when the cache is dropped.
+///
+/// Example usage:
+/// ```
+/// use rc5::{CacheStats, KeyScheduleCache};
+///
+/// let key = [
+///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+/// ];
+/// let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+/// let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+///
+/// // RC5-32/12/16 with room for 2 keys
+/// let mut cache = KeyScheduleCache::<32, 12, 16, 4, 8, 26, 4, 2>::new();
+///
+/// assert_eq!(cache.get(key).encrypt(plaintext), ciphertext);
+/// assert_eq!(cache.get(key).encrypt(plaintext), ciphertext);
+/// assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+/// ```
+pub struct KeyScheduleCache<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+    const CAPACITY: usize,
+> {
+    entries: [Option<
+        Entry<
+            WORD_BIT_SIZE,
+            ROUNDS,
+            KEY_SIZE,
+            WORD_SIZE,
+            BLOCK_SIZE,
+            EXPANDED_KEY_TABLE_LEN,
+            KEY_AS_WORDS_LEN,
+        >,
+    >; CAPACITY],
+    clock: u64,
+    stats: CacheStats,
+}
+
+struct Entry<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    key: [u8; KEY_SIZE],
+    cipher: RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >,
+    last_used: u64,
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+        const CAPACITY: usize,
+    >
+    KeyScheduleCache<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+        CAPACITY,
+    >
+{
+    /// Creates an empty cache.
+    ///
+    /// Panics if `CAPACITY` is 0.
+    pub fn new() -> Self {
+        assert!(CAPACITY > 0, "Cache capacity must not be 0.");
+
+        Self {
+            entries: [(); CAPACITY].map(|_| None),
+            clock: 0,
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Returns the cipher for the key, expanding the key and caching the cipher on a miss.
+    pub fn get(
+        &mut self,
+        key: [u8; KEY_SIZE],
+    ) -> &RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    > {
+        self.clock += 1;
+
+        let cached = self
+            .entries
+            .iter()
+            .position(|entry| matches!(entry, Some(entry) if bool::from(entry.key.ct_eq(&key))));
+        let idx = match cached {
+            Some(idx) => {
+                self.stats.hits += 1;
+                idx
+            }
+            None => {
+                self.stats.misses += 1;
+                let idx = self.eviction_candidate();
+                if let Some(entry) = &mut self.entries[idx] {
+                    entry.wipe();
+                }
+                self.entries[idx] = Some(Entry {
+                    key,
+                    cipher: RC5::new(key),
+                    last_used: 0,
+                });
+                idx
+            }
+        };
+
+        let entry = self.entries[idx].as_mut().expect("Entry is filled above.");
+        entry.last_used = self.clock;
+        &entry.cipher
+    }
+
+    /// Returns the hit and miss counters since the cache was created or the counters were reset.
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Resets the hit and miss counters, e.g. after reporting them to a metrics system.
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+
+    /// Removes all cached ciphers, wiping their keys and expanded key tables.
+    pub fn clear(&mut self) {
+        for entry in self.entries.iter_mut().flatten() {
+            entry.wipe();
+        }
+        self.entries = [(); CAPACITY].map(|_| None);
+    }
+
+    /// Returns the index of the first empty slot, or of the least recently used entry if full.
+    fn eviction_candidate(&self) -> usize {
+        self.entries
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, entry)| entry.as_ref().map_or(0, |entry| entry.last_used))
+            .map(|(idx, _)| idx)
+            .expect("Cache capacity is not 0.")
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+        const CAPACITY: usize,
+    > Default
+    for KeyScheduleCache<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+        CAPACITY,
+    >
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+        const CAPACITY: usize,
+    > Drop
+    for KeyScheduleCache<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+        CAPACITY,
+    >
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    Entry<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Zeroes the key and the expanded key table with volatile writes.
+    fn wipe(&mut self) {
+        wipe(&mut self.key);
+        self.cipher.wipe();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_schedule_cache_hits_and_misses() {
+        let mut cache = KeyScheduleCache::<32, 12, 16, 4, 8, 26, 4, 2>::new();
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+
+        assert_eq!(cache.get(key).encrypt(plaintext), ciphertext);
+        assert_eq!(cache.get(key).decrypt(ciphertext), plaintext);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+
+        cache.reset_stats();
+        assert_eq!(cache.stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn entry_wipe() {
+        let mut entry = Entry {
+            key: [0xFF; 16],
+            cipher: RC5::<32, 12, 16, 4, 8, 26, 4>::new([0xFF; 16]),
+            last_used: 1,
+        };
+
+        entry.wipe();
+        assert_eq!(entry.key, [0x00; 16]);
+        assert_eq!(entry.cipher.expanded_key_table, [[0x00; 4]; 26]);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn key_schedule_cache_evicts_least_recently_used() {
+        let mut cache = KeyScheduleCache::<8, 12, 4, 1, 2, 26, 4, 2>::new();
+        let (key_a, key_b, key_c) = ([0x00; 4], [0x01; 4], [0x02; 4]);
+
+        cache.get(key_a);
+        cache.get(key_b);
+        cache.get(key_a);
+        // Evicts key_b, which is the least recently used.
+        cache.get(key_c);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 3 });
+
+        assert!(*cache.get(key_a) == RC5::new(key_a));
+        assert!(*cache.get(key_c) == RC5::new(key_c));
+        assert_eq!(cache.stats(), CacheStats { hits: 3, misses: 3 });
+
+        assert!(*cache.get(key_b) == RC5::new(key_b));
+        assert_eq!(cache.stats(), CacheStats { hits: 3, misses: 4 });
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn key_schedule_cache_clear() {
+        let mut cache = KeyScheduleCache::<8, 12, 4, 1, 2, 26, 4, 1>::new();
+
+        cache.get([0x00; 4]);
+        cache.clear();
+        cache.get([0x00; 4]);
+        assert_eq!(cache.stats(), CacheStats { hits: 0, misses: 2 });
+    }
+}
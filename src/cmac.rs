@@ -0,0 +1,111 @@
+use crate::words::{gf_double, gf_polynomial, BigEndianByteIntegerExt, ByteIntegerExt};
+
+/// Returns whether CMAC is defined for the block size, i.e. whether [`gf_double`] supports it.
+pub(crate) const fn is_supported(block_size: usize) -> bool {
+    gf_polynomial(8 * block_size).is_some()
+}
+
+/// Computes the CMAC (NIST SP 800-38B) of the message made up of the concatenated `parts`.
+///
+/// As in the specification, blocks are treated as big-endian bit strings when deriving the
+/// subkeys.
+///
+/// Panics if the block bit size is not supported by [`gf_double`].
+pub(crate) fn cmac<const BLOCK_SIZE: usize>(
+    encrypt: impl Fn([u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE],
+    parts: &[&[u8]],
+) -> [u8; BLOCK_SIZE] {
+    let subkey1 = double(encrypt([0; BLOCK_SIZE]));
+    let subkey2 = double(subkey1);
+
+    let message_len: usize = parts.iter().map(|part| part.len()).sum();
+    // The empty message is processed as a single, padded block.
+    let num_blocks = message_len.saturating_sub(1) / BLOCK_SIZE + 1;
+    let mut message = parts.iter().flat_map(|part| part.iter().copied());
+
+    let mut state = [0; BLOCK_SIZE];
+    for _ in 1..num_blocks {
+        let mut block = [0; BLOCK_SIZE];
+        for (byte, message_byte) in block.iter_mut().zip(&mut message) {
+            *byte = message_byte;
+        }
+        state = encrypt(state.bitxor(block));
+    }
+
+    // The last block is masked with the first subkey if it is complete, otherwise it is padded
+    // with a single one bit followed by zeros and masked with the second subkey.
+    let mut last_block = [0; BLOCK_SIZE];
+    let mut last_block_len = 0;
+    for (byte, message_byte) in last_block.iter_mut().zip(&mut message) {
+        *byte = message_byte;
+        last_block_len += 1;
+    }
+    let last_block = if last_block_len == BLOCK_SIZE {
+        last_block.bitxor(subkey1)
+    } else {
+        last_block[last_block_len] = 0x80;
+        last_block.bitxor(subkey2)
+    };
+
+    encrypt(state.bitxor(last_block))
+}
+
+/// Doubles a block interpreted as a big-endian bit string in GF(2^n).
+fn double<const BLOCK_SIZE: usize>(block: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+    gf_double(<[u8; BLOCK_SIZE]>::from_be_slice(&block)).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RC5;
+
+    // Expected values are generated by `python3 scripts/reference_vectors.py cmac`.
+
+    #[test]
+    fn cmac_rc_32_12_16_empty() {
+        assert_cmac(&[], [0x06, 0xC0, 0x02, 0xBD, 0xB0, 0x8B, 0x3C, 0x68]);
+    }
+
+    #[test]
+    fn cmac_rc_32_12_16_single_block() {
+        assert_cmac(
+            &[&[0x00, 0x01, 0x02, 0x03], &[0x04, 0x05, 0x06, 0x07]],
+            [0xD2, 0x04, 0xDC, 0x3D, 0x86, 0x3C, 0xB3, 0x07],
+        );
+    }
+
+    #[test]
+    fn cmac_rc_32_12_16_partial_block() {
+        assert_cmac(
+            &[&[
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C,
+            ]],
+            [0x42, 0xFA, 0x81, 0x46, 0xEB, 0x42, 0xF0, 0x7D],
+        );
+    }
+
+    #[test]
+    fn cmac_rc_32_12_16_two_blocks() {
+        assert_cmac(
+            &[
+                &[0x00, 0x01, 0x02],
+                &[],
+                &[
+                    0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+                ],
+            ],
+            [0x8D, 0xE8, 0x2D, 0xBB, 0xCE, 0x31, 0x30, 0x29],
+        );
+    }
+
+    fn assert_cmac(parts: &[&[u8]], expected: [u8; 8]) {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(key);
+
+        assert_eq!(cmac(|block| rc5.encrypt(block), parts), expected);
+    }
+}
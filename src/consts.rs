@@ -1,65 +1,79 @@
-use core::cmp::max;
-
-use num_bigint::BigInt;
-use num_rational::BigRational;
-use num_traits::{FromPrimitive, One, ToPrimitive, Zero};
-
-use crate::bytes::ByteIntegerExt;
-
-pub fn p<const WBIT: usize, const WBYTE: usize>() -> [u8; WBYTE] {
-    // Number of terms to include in the series.
-    const TERMS: u64 = 34;
-    let e = approximate_e(TERMS);
-
-    let result: BigRational = (e - big_rational_two()) * big_rational_two().pow(WBIT as i32);
-
-    let result = result.to_u128().expect("Unable to represent constant p.");
-
-    <[u8; WBYTE]>::from_slice(&odd(result).to_le_bytes())
+/// Fractional part of e (e - 2) as a 128-bit fixed-point number, rounded down.
+const E_FRACTION: u128 = 0xB7E1_5162_8AED_2A6A_BF71_5880_9CF4_F3C7;
+/// Fractional part of the golden ratio (phi - 1) as a 128-bit fixed-point number, rounded down.
+const PHI_FRACTION: u128 = 0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C834;
+/// Fractional part of pi (pi - 3) as a 128-bit fixed-point number, rounded down.
+const PI_FRACTION: u128 = 0x243F_6A88_85A3_08D3_1319_8A2E_0370_7344;
+/// Fractional part of the square root of 2 (sqrt(2) - 1) as a 128-bit fixed-point number, rounded
+/// down.
+const SQRT2_FRACTION: u128 = 0x6A09_E667_F3BC_C908_B2FB_1366_EA95_7D3E;
+
+/// Irrational constants the magic constants of the key schedule can be derived from.
+///
+/// RC5 derives P from e and Q from the golden ratio. Some derivatives of RC5 found in the wild
+/// use other constants instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MagicConstant {
+    /// e, from which RC5 derives P.
+    E,
+    /// The golden ratio, from which RC5 derives Q.
+    GoldenRatio,
+    /// pi.
+    Pi,
+    /// The square root of 2.
+    Sqrt2,
 }
 
-pub fn q<const WBIT: usize, const WBYTE: usize>() -> [u8; WBYTE] {
-    // Number of terms to include in the series.
-    const TERMS: u64 = 93;
-    let phi = approximate_golden_ratio(TERMS);
-
-    let result = (phi - BigRational::one()) * big_rational_two().pow(WBIT as i32);
-
-    let result = result.to_u128().expect("Unable to represent constant q.");
-
-    <[u8; WBYTE]>::from_slice(&odd(result).to_le_bytes())
+impl MagicConstant {
+    /// Derives the magic constant Odd(f * 2^WBIT) for the fractional part f of the irrational
+    /// constant as a little-endian word.
+    ///
+    /// Panics if `WBIT` is larger than 128.
+    pub const fn derive<const WBIT: usize, const WBYTE: usize>(self) -> [u8; WBYTE] {
+        let fraction = match self {
+            MagicConstant::E => E_FRACTION,
+            MagicConstant::GoldenRatio => PHI_FRACTION,
+            MagicConstant::Pi => PI_FRACTION,
+            MagicConstant::Sqrt2 => SQRT2_FRACTION,
+        };
+        magic_constant::<WBIT, WBYTE>(fraction)
+    }
 }
 
-fn odd(value: u128) -> u128 {
-    if value % 2 == 0 {
-        value + 1
-    } else {
-        value
-    }
+pub const fn p<const WBIT: usize, const WBYTE: usize>() -> [u8; WBYTE] {
+    MagicConstant::E.derive::<WBIT, WBYTE>()
 }
 
-fn big_rational_two() -> BigRational {
-    // TODO: Recreate without unwrap.
-    BigRational::from_u8(2).unwrap()
+pub const fn q<const WBIT: usize, const WBYTE: usize>() -> [u8; WBYTE] {
+    MagicConstant::GoldenRatio.derive::<WBIT, WBYTE>()
 }
 
-fn approximate_e(terms: u64) -> BigRational {
-    let mut e = BigRational::zero();
-    let mut factorial = BigInt::one();
-    for idx in 0..terms {
-        factorial *= max(idx, 1);
-        let term = BigRational::from_integer(BigInt::one()) / &factorial;
-        e += term;
+/// Computes Odd(f * 2^WBIT) for the fractional part f of an irrational constant.
+///
+/// The constants are precomputed with 128 bits of precision so that deriving them requires neither
+/// arbitrary precision arithmetic nor heap allocations. Rounding f * 2^128 down and then dropping
+/// the low bits yields the same result as rounding f * 2^WBIT down directly. The derivation is a
+/// `const fn` so that the constants can be evaluated at compile time.
+const fn magic_constant<const WBIT: usize, const WBYTE: usize>(fraction: u128) -> [u8; WBYTE] {
+    assert!(
+        WBIT <= u128::BITS as usize,
+        "Unable to represent magic constants for words larger than 128 bits."
+    );
+
+    let result = odd(fraction >> (u128::BITS as usize - WBIT)).to_le_bytes();
+
+    let mut output = [0; WBYTE];
+    let mut idx = 0;
+    while idx < WBYTE && idx < result.len() {
+        output[idx] = result[idx];
+        idx += 1;
     }
-    e
+
+    output
 }
 
-fn approximate_golden_ratio(terms: u64) -> BigRational {
-    let mut phi = BigRational::zero();
-    for _ in 0..terms {
-        phi = BigRational::one() / (BigRational::one() + phi);
-    }
-    phi + BigRational::one()
+const fn odd(value: u128) -> u128 {
+    value | 1
 }
 
 #[cfg(test)]
@@ -86,6 +100,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn p_128() {
+        assert_eq!(
+            p::<128, 16>(),
+            [
+                0xC7, 0xF3, 0xF4, 0x9C, 0x80, 0x58, 0x71, 0xBF, 0x6A, 0x2A, 0xED, 0x8A, 0x62, 0x51,
+                0xE1, 0xB7
+            ]
+        );
+    }
+
+    #[test]
+    fn pi_32() {
+        assert_eq!(
+            MagicConstant::Pi.derive::<32, 4>(),
+            0x243F_6A89u32.to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn sqrt2_32() {
+        assert_eq!(
+            MagicConstant::Sqrt2.derive::<32, 4>(),
+            0x6A09_E667u32.to_le_bytes()
+        );
+    }
+
     #[test]
     fn q_16() {
         assert_eq!(q::<16, 2>(), [0x37, 0x9E]);
@@ -103,4 +144,68 @@ mod tests {
             [0x15, 0x7C, 0x4A, 0x7F, 0xB9, 0x79, 0x37, 0x9E]
         );
     }
+
+    #[test]
+    fn q_128() {
+        assert_eq!(
+            q::<128, 16>(),
+            [
+                0x35, 0xC8, 0xED, 0x5C, 0x60, 0xC0, 0x9C, 0xF3, 0x15, 0x7C, 0x4A, 0x7F, 0xB9, 0x79,
+                0x37, 0x9E
+            ]
+        );
+    }
+
+    #[test]
+    fn p_q_all_word_sizes() {
+        // Reference values computed from e and phi with 100 significant digits of precision.
+        assert_magic_constants::<8, 1>("B7", "9F");
+        assert_magic_constants::<16, 2>("B7E1", "9E37");
+        assert_magic_constants::<24, 3>("B7E151", "9E3779");
+        assert_magic_constants::<32, 4>("B7E15163", "9E3779B9");
+        assert_magic_constants::<40, 5>("B7E151628B", "9E3779B97F");
+        assert_magic_constants::<48, 6>("B7E151628AED", "9E3779B97F4B");
+        assert_magic_constants::<56, 7>("B7E151628AED2B", "9E3779B97F4A7D");
+        assert_magic_constants::<64, 8>("B7E151628AED2A6B", "9E3779B97F4A7C15");
+        assert_magic_constants::<72, 9>("B7E151628AED2A6ABF", "9E3779B97F4A7C15F3");
+        assert_magic_constants::<80, 10>("B7E151628AED2A6ABF71", "9E3779B97F4A7C15F39D");
+        assert_magic_constants::<88, 11>("B7E151628AED2A6ABF7159", "9E3779B97F4A7C15F39CC1");
+        assert_magic_constants::<96, 12>("B7E151628AED2A6ABF715881", "9E3779B97F4A7C15F39CC061");
+        assert_magic_constants::<104, 13>(
+            "B7E151628AED2A6ABF7158809D",
+            "9E3779B97F4A7C15F39CC0605D",
+        );
+        assert_magic_constants::<112, 14>(
+            "B7E151628AED2A6ABF7158809CF5",
+            "9E3779B97F4A7C15F39CC0605CED",
+        );
+        assert_magic_constants::<120, 15>(
+            "B7E151628AED2A6ABF7158809CF4F3",
+            "9E3779B97F4A7C15F39CC0605CEDC9",
+        );
+        assert_magic_constants::<128, 16>(
+            "B7E151628AED2A6ABF7158809CF4F3C7",
+            "9E3779B97F4A7C15F39CC0605CEDC835",
+        );
+    }
+
+    fn assert_magic_constants<const WBIT: usize, const WBYTE: usize>(
+        expected_p: &str,
+        expected_q: &str,
+    ) {
+        assert_eq!(p::<WBIT, WBYTE>(), parse_hex(expected_p), "P{WBIT}");
+        assert_eq!(q::<WBIT, WBYTE>(), parse_hex(expected_q), "Q{WBIT}");
+    }
+
+    /// Parses a big-endian hex string into a little-endian word.
+    fn parse_hex<const WBYTE: usize>(hex: &str) -> [u8; WBYTE] {
+        assert_eq!(hex.len(), 2 * WBYTE);
+
+        let mut output = [0; WBYTE];
+        for (byte, idx) in output.iter_mut().zip((0..hex.len()).step_by(2).rev()) {
+            *byte = u8::from_str_radix(&hex[idx..idx + 2], 16).unwrap();
+        }
+
+        output
+    }
 }
@@ -0,0 +1,147 @@
+use crate::{arx::rotation_amount, words::ByteIntegerExt, RC5};
+
+/// Provides RC5 decryption with the expanded key table stored in reverse order.
+///
+/// Decryption consumes the expanded key table from the last word to the first. Storing it reversed
+/// lets the decryption loop walk memory forward, which can help cache prefetching when decrypting
+/// many blocks, e.g. in CBC mode. It also suits components that only ever decrypt, see
+/// [`crate::RC5Encryptor`]. Create one with [`RC5Decryptor::new`] or [`RC5::decryptor`].
+pub struct RC5Decryptor<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    // The wrapped cipher holds the reversed expanded key table and must not be used to encrypt.
+    reversed: RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >,
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Returns a decryption-only view of the cipher with the expanded key table laid out in the
+    /// order decryption consumes it.
+    pub fn decryptor(
+        &self,
+    ) -> RC5Decryptor<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    > {
+        let mut expanded_key_table = self.expanded_key_table;
+        expanded_key_table.reverse();
+        RC5Decryptor {
+            reversed: self.with_expanded_key_table(expanded_key_table),
+        }
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5Decryptor<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        RC5::new(key).decryptor()
+    }
+
+    /// Decrypts the ciphertext block returning plaintext block.
+    pub fn decrypt(&self, ciphertext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let table = &self.reversed.expanded_key_table;
+        let [mut a, mut b] = self.reversed.load_block(ciphertext);
+
+        // Round ROUNDS - idx uses the words 2 * idx and 2 * idx + 1 of the reversed table.
+        for idx in 0..ROUNDS {
+            b = b
+                .wrapping_sub(table[2 * idx])
+                .rotate_right(rotation_amount(a))
+                .bitxor(a);
+            a = a
+                .wrapping_sub(table[2 * idx + 1])
+                .rotate_right(rotation_amount(b))
+                .bitxor(b);
+        }
+
+        b = b.wrapping_sub(table[2 * ROUNDS]);
+        a = a.wrapping_sub(table[2 * ROUNDS + 1]);
+
+        self.reversed.store_block([a, b])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rc_32_12_16_decryptor() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+
+        let decryptor = RC5::<32, 12, 16, 4, 8, 26, 4>::new(key).decryptor();
+        assert_eq!(decryptor.decrypt(ciphertext), plaintext);
+
+        let decryptor = RC5Decryptor::<32, 12, 16, 4, 8, 26, 4>::new(key);
+        assert_eq!(decryptor.decrypt(ciphertext), plaintext);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_8_12_4_decryptor_swapped_halves() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]).with_swapped_halves();
+        let decryptor = rc5.decryptor();
+
+        for block in [[0x00, 0x01], [0x21, 0x2A], [0xFF, 0x00]] {
+            assert_eq!(decryptor.decrypt(block), rc5.decrypt(block));
+        }
+    }
+}
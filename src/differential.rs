@@ -0,0 +1,207 @@
+use crate::{words::ByteIntegerExt, RC5};
+
+/// XOR differences of the A and B halves after every round that a pair of blocks with a given input
+/// difference is expected to follow through the cipher.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Characteristic<const WORD_SIZE: usize, const BLOCK_SIZE: usize, const ROUNDS: usize> {
+    input_difference: [u8; BLOCK_SIZE],
+    round_differences: [[[u8; WORD_SIZE]; 2]; ROUNDS],
+    num_followed: usize,
+    num_pairs: usize,
+}
+
+impl<const WORD_SIZE: usize, const BLOCK_SIZE: usize, const ROUNDS: usize>
+    Characteristic<WORD_SIZE, BLOCK_SIZE, ROUNDS>
+{
+    /// XOR difference between the plaintexts of a pair.
+    pub fn input_difference(&self) -> [u8; BLOCK_SIZE] {
+        self.input_difference
+    }
+
+    /// XOR differences of the A and B halves after every round, starting with the first.
+    pub fn round_differences(&self) -> &[[[u8; WORD_SIZE]; 2]; ROUNDS] {
+        &self.round_differences
+    }
+
+    /// Number of sampled pairs that followed the characteristic through every round.
+    pub fn num_followed(&self) -> usize {
+        self.num_followed
+    }
+
+    /// Number of sampled pairs.
+    pub fn num_pairs(&self) -> usize {
+        self.num_pairs
+    }
+
+    /// Estimated probability of the characteristic, i.e. the fraction of the sampled pairs that
+    /// followed it.
+    pub fn probability(&self) -> f64 {
+        if self.num_pairs == 0 {
+            0.0
+        } else {
+            self.num_followed as f64 / self.num_pairs as f64
+        }
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Searches for the differential characteristic with the highest empirical probability over
+    /// this (typically reduced-round) cipher.
+    ///
+    /// Every sampled plaintext P is paired with P ^ Δ for each candidate input difference Δ, and
+    /// both are encrypted while recording the XOR differences of the A and B halves after every
+    /// round. The characteristic of a candidate is built greedily, one round at a time, from the
+    /// most frequent difference among the pairs that followed the characteristic up to that round,
+    /// with ties going to the earliest plaintext. The characteristic followed by the most pairs is
+    /// returned, with ties going to the earliest candidate.
+    ///
+    /// The greedy search is a heuristic and may miss characteristics that start with a less likely
+    /// round. The probabilities are estimated for the key of this cipher, so sample several keys to
+    /// reproduce key-independent results. Returns `None` if there are no candidates.
+    pub fn search_differential_characteristic<const PAIRS: usize>(
+        &self,
+        plaintexts: &[[u8; BLOCK_SIZE]; PAIRS],
+        input_differences: impl IntoIterator<Item = [u8; BLOCK_SIZE]>,
+    ) -> Option<Characteristic<WORD_SIZE, BLOCK_SIZE, ROUNDS>> {
+        let mut best: Option<Characteristic<WORD_SIZE, BLOCK_SIZE, ROUNDS>> = None;
+
+        for input_difference in input_differences {
+            let traces = plaintexts.map(|plaintext| {
+                self.trace_differences(plaintext, plaintext.bitxor(input_difference))
+            });
+
+            let mut round_differences = [[[0; WORD_SIZE]; 2]; ROUNDS];
+            let mut followed = [true; PAIRS];
+            for (round, round_difference) in round_differences.iter_mut().enumerate() {
+                let mut max_count = 0;
+                for (trace, _) in traces
+                    .iter()
+                    .zip(followed)
+                    .filter(|(_, followed)| *followed)
+                {
+                    let count = traces
+                        .iter()
+                        .zip(followed)
+                        .filter(|(other, followed)| *followed && other[round] == trace[round])
+                        .count();
+                    if count > max_count {
+                        max_count = count;
+                        *round_difference = trace[round];
+                    }
+                }
+
+                for (trace, followed) in traces.iter().zip(followed.iter_mut()) {
+                    *followed &= trace[round] == *round_difference;
+                }
+            }
+
+            let num_followed = followed.iter().filter(|followed| **followed).count();
+            if !matches!(best, Some(best) if best.num_followed >= num_followed) {
+                best = Some(Characteristic {
+                    input_difference,
+                    round_differences,
+                    num_followed,
+                    num_pairs: PAIRS,
+                });
+            }
+        }
+
+        best
+    }
+
+    /// Returns the XOR differences of the A and B halves after every round for a pair of plaintexts.
+    fn trace_differences(
+        &self,
+        plaintext: [u8; BLOCK_SIZE],
+        other_plaintext: [u8; BLOCK_SIZE],
+    ) -> [[[u8; WORD_SIZE]; 2]; ROUNDS] {
+        let mut states = [[[0; WORD_SIZE]; 2]; ROUNDS];
+        self.encrypt_traced(plaintext, |round, a, b| {
+            if round > 0 {
+                states[round - 1] = [*a, *b];
+            }
+        });
+
+        let mut differences = [[[0; WORD_SIZE]; 2]; ROUNDS];
+        self.encrypt_traced(other_plaintext, |round, a, b| {
+            if round > 0 {
+                let [state_a, state_b] = states[round - 1];
+                differences[round - 1] = [state_a.bitxor(*a), state_b.bitxor(*b)];
+            }
+        });
+        differences
+    }
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+mod tests {
+    use super::*;
+
+    // Expected values are generated by `python3 scripts/reference_vectors.py differential`.
+
+    fn plaintexts() -> [[u8; 4]; 64] {
+        core::array::from_fn(|idx| (idx as u32).wrapping_mul(0x9E37_79B9).to_le_bytes())
+    }
+
+    #[test]
+    fn search_differential_characteristic_rc_16_1_8() {
+        let rc5 =
+            RC5::<16, 1, 8, 2, 4, 4, 4>::new([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]);
+        // Flipping the most significant bit of both halves cancels out in A ^ B and leaves the
+        // rotation amounts unchanged, so A follows with certainty and B whenever A rotates by 0.
+        let input_differences = (0..32)
+            .map(|bit| (1u32 << bit).to_le_bytes())
+            .chain([[0x00, 0x80, 0x00, 0x80]]);
+
+        let characteristic = rc5
+            .search_differential_characteristic(&plaintexts(), input_differences)
+            .unwrap();
+
+        assert_eq!(characteristic.input_difference(), [0x00, 0x80, 0x00, 0x80]);
+        assert_eq!(
+            characteristic.round_differences(),
+            &[[[0x00, 0x00], [0x00, 0x80]]]
+        );
+        assert_eq!(characteristic.num_followed(), 8);
+        assert_eq!(characteristic.num_pairs(), 64);
+        assert_eq!(characteristic.probability(), 0.125);
+    }
+
+    #[test]
+    fn search_differential_characteristic_zero_difference() {
+        let rc5 = RC5::<16, 2, 8, 2, 4, 6, 4>::new([0x00; 8]);
+
+        let characteristic = rc5
+            .search_differential_characteristic(&plaintexts(), [[0x00; 4]])
+            .unwrap();
+
+        assert_eq!(characteristic.round_differences(), &[[[0x00; 2]; 2]; 2]);
+        assert_eq!(characteristic.probability(), 1.0);
+    }
+
+    #[test]
+    fn search_differential_characteristic_no_candidates() {
+        let rc5 = RC5::<16, 2, 8, 2, 4, 6, 4>::new([0x00; 8]);
+        assert!(rc5
+            .search_differential_characteristic(&plaintexts(), [])
+            .is_none());
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    cmac::{self, cmac},
+    RC5,
+};
+
+/// Prefix of the CMAC inputs of [`RC5::derive_subkeys`], separating subkeys from child keys.
+const SUBKEY_CONTEXT: &[u8] = b"subkey\0";
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Fails to compile when CMAC, which the key derivation functions build on, is not defined for
+    /// the block size.
+    pub(crate) const CMAC_SUPPORTED: () = assert!(
+        cmac::is_supported(BLOCK_SIZE),
+        "CMAC requires a block bit size that is a power of two between 16 and 1024."
+    );
+
+    /// Derives a child key from this cipher's (master) key and a diversifier, such as a device
+    /// serial number or a session identifier.
+    ///
+    /// The child key is derived with CMAC as the pseudorandom function in a counter mode construction
+    /// similar to NIST SP 800-108: it consists of the blocks CMAC(i || diversifier) for a one byte
+    /// counter i starting at 1, truncated to the key size. Unlike SP 800-108, the input contains
+    /// neither a separator byte nor the encoded output length, so the derived keys do not match
+    /// those of SP 800-108 implementations.
+    ///
+    /// Fails to compile if the block bit size is not a power of two between 16 and 1024, e.g. for
+    /// RC5-24:
+    /// ```compile_fail
+    /// use rc5::RC5;
+    ///
+    /// // RC5-24/4/0
+    /// RC5::<24, 4, 0, 3, 6, 10, 1>::new([]).derive_child_key(b"device-0001");
+    /// ```
+    pub fn derive_child_key(&self, diversifier: &[u8]) -> [u8; KEY_SIZE] {
+        let () = Self::CMAC_SUPPORTED;
+        self.derive_child_key_unchecked(diversifier)
+    }
+
+    /// Derives a child key as [`RC5::derive_child_key`] does, without checking the block size at
+    /// compile time.
+    ///
+    /// Panics if CMAC is not defined for the block size.
+    pub(crate) fn derive_child_key_unchecked(&self, diversifier: &[u8]) -> [u8; KEY_SIZE] {
+        self.derive_key(&[], diversifier)
+    }
+
+    /// Derives a key from the blocks CMAC(i || context || input), see [`RC5::derive_child_key`].
+    fn derive_key(&self, context: &[u8], input: &[u8]) -> [u8; KEY_SIZE] {
+        let mut key = [0; KEY_SIZE];
+
+        for (counter, chunk) in (1..=u8::MAX).zip(key.chunks_mut(BLOCK_SIZE)) {
+            let block = cmac(|block| self.encrypt(block), &[&[counter], context, input]);
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+
+        key
+    }
+
+    /// Derives one independent key per label from this cipher's (master) key, e.g. separate
+    /// encryption and MAC keys, so that a single key is never used for both purposes.
+    ///
+    /// Each key is derived like a child key (see [`RC5::derive_child_key`]) from the blocks
+    /// CMAC(i || "subkey" || 0x00 || label), so that subkeys are separated from the child keys of
+    /// ordinary diversifiers, e.g. device identifiers. Keys derived for distinct labels are
+    /// independent. Diversifiers starting with "subkey" followed by a zero byte are reserved for
+    /// subkeys and must not be passed to [`RC5::derive_child_key`].
+    ///
+    /// Panics if a label occurs more than once. Fails to compile under the same conditions as
+    /// [`RC5::derive_child_key`].
+    ///
+    /// Example usage:
+    /// ```
+    /// use rc5::RC5;
+    ///
+    /// // RC5-32/12/16
+    /// let master = RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]);
+    ///
+    /// let [encryption_key, mac_key] = master.derive_subkeys(["enc", "mac"]);
+    /// assert_ne!(encryption_key, mac_key);
+    /// ```
+    pub fn derive_subkeys<const N: usize>(&self, labels: [&str; N]) -> [[u8; KEY_SIZE]; N] {
+        for (idx, label) in labels.iter().enumerate() {
+            assert!(
+                !labels[..idx].contains(label),
+                "Subkey labels must be distinct."
+            );
+        }
+
+        let () = Self::CMAC_SUPPORTED;
+        labels.map(|label| self.derive_key(SUBKEY_CONTEXT, label.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Expected values are generated by `python3 scripts/reference_vectors.py diversify`.
+
+    const MASTER_KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+        0x0F,
+    ];
+
+    #[test]
+    fn derive_child_key_rc_32_12_16_a() {
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::new(MASTER_KEY).derive_child_key(b"device-0001"),
+            [
+                0xB6, 0x29, 0x9F, 0xFC, 0xF6, 0x9E, 0x28, 0xA1, 0x35, 0xDB, 0xA1, 0x01, 0x2F, 0x03,
+                0xF3, 0x79
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_child_key_rc_32_12_16_b() {
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::new(MASTER_KEY).derive_child_key(b"device-0002"),
+            [
+                0x56, 0x96, 0xB2, 0xDE, 0x55, 0xDA, 0xF4, 0xC3, 0xE7, 0xD3, 0xAE, 0x43, 0x85, 0xA9,
+                0x8E, 0xDE
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_child_key_rc_32_12_16_empty_diversifier() {
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::new(MASTER_KEY).derive_child_key(&[]),
+            [
+                0xCB, 0xCB, 0xDB, 0x54, 0xA6, 0x76, 0xFE, 0x5B, 0xB8, 0x02, 0x0A, 0xAB, 0x34, 0x5A,
+                0x2A, 0xC7
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_subkeys_rc_32_12_16() {
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::new(MASTER_KEY).derive_subkeys(["enc", "mac"]),
+            [
+                [
+                    0xD5, 0x48, 0xB6, 0x39, 0xC0, 0x82, 0x40, 0xA3, 0x0D, 0x58, 0xED, 0xF8, 0x50,
+                    0xBD, 0x3B, 0x3E
+                ],
+                [
+                    0x33, 0x52, 0x47, 0x1A, 0x95, 0xCC, 0xA0, 0xCA, 0xE7, 0xD1, 0x3E, 0x0C, 0x08,
+                    0x3F, 0x37, 0x0C
+                ]
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_subkeys_separated_from_child_keys() {
+        let master = RC5::<32, 12, 16, 4, 8, 26, 4>::new(MASTER_KEY);
+        let [chain_key, encryption_key] = master.derive_subkeys(["chain", "enc"]);
+
+        assert_ne!(chain_key, master.derive_child_key(b"chain"));
+        assert_ne!(encryption_key, master.derive_child_key(b"enc"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn derive_subkeys_duplicate_labels() {
+        RC5::<32, 12, 16, 4, 8, 26, 4>::new(MASTER_KEY).derive_subkeys(["enc", "mac", "enc"]);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::RC5;
+
+/// Provides RC5 encryption only.
+///
+/// Use it together with [`crate::RC5Decryptor`] when a component only ever processes data in one
+/// direction, e.g. firmware that only decrypts update payloads. Since generic code is only compiled
+/// for the methods that are called, the unused direction does not end up in the binary. Create one
+/// with [`RC5Encryptor::new`] or [`RC5::encryptor`].
+pub struct RC5Encryptor<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    cipher: RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >,
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Returns an encryption-only view of the cipher.
+    pub fn encryptor(
+        &self,
+    ) -> RC5Encryptor<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    > {
+        RC5Encryptor {
+            cipher: self.with_expanded_key_table(self.expanded_key_table),
+        }
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5Encryptor<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        Self {
+            cipher: RC5::new(key),
+        }
+    }
+
+    /// Encrypts the plaintext block returning ciphertext block.
+    pub fn encrypt(&self, plaintext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        self.cipher.encrypt(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rc_32_12_16_encryptor() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+
+        let encryptor = RC5Encryptor::<32, 12, 16, 4, 8, 26, 4>::new(key);
+        assert_eq!(encryptor.encrypt(plaintext), ciphertext);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_8_12_4_encryptor_swapped_halves() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]).with_swapped_halves();
+        let encryptor = rc5.encryptor();
+
+        for block in [[0x00, 0x01], [0x21, 0x2A], [0xFF, 0x00]] {
+            assert_eq!(encryptor.encrypt(block), rc5.encrypt(block));
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use core::fmt;
+
+/// Errors returned by the fallible operations of this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The provided output buffer is too small to hold the result.
+    BufferTooSmall,
+    /// The input has an unexpected length.
+    InvalidLength,
+    /// The input does not start with the expected magic bytes.
+    InvalidMagic,
+    /// The input was encoded with an unsupported format version.
+    UnsupportedVersion,
+    /// The RC5 parameters cannot be encoded or do not match the expected parameters.
+    ParameterMismatch,
+    /// The operation is not supported for the RC5 parameters.
+    UnsupportedParameters,
+    /// The key is shorter than allowed by the `strict` feature.
+    WeakKey,
+    /// The input contains symbols outside of the expected alphabet.
+    InvalidEncoding,
+    /// The check symbol of the input does not match, e.g. because of a typo.
+    InvalidChecksum,
+    /// The license code was not issued under this key or has been tampered with.
+    InvalidLicense,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Error::BufferTooSmall => "buffer too small",
+            Error::InvalidLength => "invalid length",
+            Error::InvalidMagic => "invalid magic bytes",
+            Error::UnsupportedVersion => "unsupported format version",
+            Error::ParameterMismatch => "parameter mismatch",
+            Error::UnsupportedParameters => "unsupported parameters",
+            Error::WeakKey => "weak key",
+            Error::InvalidEncoding => "invalid encoding",
+            Error::InvalidChecksum => "invalid checksum",
+            Error::InvalidLicense => "invalid license",
+        };
+        f.write_str(message)
+    }
+}
@@ -0,0 +1,102 @@
+use core::marker::PhantomData;
+
+use generic_array::{ArrayLength, GenericArray};
+
+use crate::RC5;
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Creates the cipher from a key sized with typenum.
+    ///
+    /// Fails to compile if `N` does not match `KEY_SIZE`:
+    /// ```compile_fail
+    /// use generic_array::{typenum::U8, GenericArray};
+    /// use rc5::RC5;
+    ///
+    /// // RC5-32/12/16
+    /// RC5::<32, 12, 16, 4, 8, 26, 4>::new_from_generic_array(&GenericArray::<u8, U8>::default());
+    /// ```
+    pub fn new_from_generic_array<N: ArrayLength>(key: &GenericArray<u8, N>) -> Self {
+        Self::new(to_array(key))
+    }
+
+    /// Encrypts the block sized with typenum in place, as RustCrypto block ciphers do.
+    ///
+    /// Fails to compile if `N` does not match `BLOCK_SIZE`:
+    /// ```compile_fail
+    /// use generic_array::{typenum::U4, GenericArray};
+    /// use rc5::RC5;
+    ///
+    /// // RC5-32/12/16
+    /// let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]);
+    /// rc5.encrypt_generic_array(&mut GenericArray::<u8, U4>::default());
+    /// ```
+    pub fn encrypt_generic_array<N: ArrayLength>(&self, block: &mut GenericArray<u8, N>) {
+        let output = self.encrypt(to_array(block));
+        block.copy_from_slice(&output);
+    }
+
+    /// Decrypts the block sized with typenum in place, as RustCrypto block ciphers do.
+    ///
+    /// Fails to compile if `N` does not match `BLOCK_SIZE`.
+    pub fn decrypt_generic_array<N: ArrayLength>(&self, block: &mut GenericArray<u8, N>) {
+        let output = self.decrypt(to_array(block));
+        block.copy_from_slice(&output);
+    }
+}
+
+/// Checks at compile time that the typenum length `N` matches `SIZE`.
+struct SizeCheck<const SIZE: usize, N>(PhantomData<N>);
+
+impl<const SIZE: usize, N: ArrayLength> SizeCheck<SIZE, N> {
+    const MATCHES: () = assert!(N::USIZE == SIZE, "Generic array size does not match.");
+}
+
+fn to_array<const SIZE: usize, N: ArrayLength>(value: &GenericArray<u8, N>) -> [u8; SIZE] {
+    let () = SizeCheck::<SIZE, N>::MATCHES;
+
+    let mut output = [0; SIZE];
+    output.copy_from_slice(value);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use generic_array::typenum::{U16, U8};
+
+    use super::*;
+
+    #[test]
+    fn rc_32_12_16_encrypt_decrypt_generic_array() {
+        let key = GenericArray::<u8, U16>::from([
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ]);
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new_from_generic_array(&key);
+
+        let mut block = GenericArray::<u8, U8>::from(plaintext);
+        rc5.encrypt_generic_array(&mut block);
+        assert_eq!(block.as_slice(), ciphertext);
+        rc5.decrypt_generic_array(&mut block);
+        assert_eq!(block.as_slice(), plaintext);
+    }
+}
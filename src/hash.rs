@@ -0,0 +1,166 @@
+use crate::{arx::join_words, words::ByteIntegerExt, RC5};
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Hashes the message into a digest of twice the block size with the Hirose double-block-length
+    /// construction, e.g. for integrity tags wider than a single block of the small-block variants.
+    ///
+    /// The message is padded like in Merkle-Damgård hashes (a one bit, zeros and the 64-bit
+    /// big-endian message bit length) and split into blocks of `KEY_SIZE - BLOCK_SIZE` bytes. For
+    /// each message block M, the chaining values G and H are updated with the cipher keyed with
+    /// H || M:
+    ///
+    /// - G' = E(G) ^ G
+    /// - H' = E(G ^ c) ^ G ^ c
+    ///
+    /// where c is the constant 1. G and H start as blocks filled with the magic constants P_w and
+    /// Q_w respectively, and the digest is G || H. The security proof of the construction assumes
+    /// an ideal block cipher, which RC5 with its simple key schedule is not known to be, so this is
+    /// no replacement for a vetted hash function.
+    ///
+    /// Panics if `OUTPUT_SIZE` is not twice the block size or if the key is not larger than the
+    /// block.
+    ///
+    /// Example usage:
+    /// ```
+    /// use rc5::RC5;
+    ///
+    /// // RC5-32/12/16
+    /// let digest = RC5::<32, 12, 16, 4, 8, 26, 4>::hirose_hash::<16>(b"abc");
+    ///
+    /// assert_eq!(
+    ///     digest,
+    ///     [
+    ///         0x0A, 0x49, 0xF5, 0x05, 0x6F, 0x0D, 0x01, 0x92, 0xAC, 0x75, 0xEC, 0x3F, 0x96, 0x0C,
+    ///         0xC1, 0xAF
+    ///     ]
+    /// );
+    /// ```
+    pub fn hirose_hash<const OUTPUT_SIZE: usize>(message: &[u8]) -> [u8; OUTPUT_SIZE] {
+        assert_eq!(
+            OUTPUT_SIZE,
+            2 * BLOCK_SIZE,
+            "Digest size must be twice the block size."
+        );
+        assert!(
+            KEY_SIZE > BLOCK_SIZE,
+            "Key size must be larger than the block size."
+        );
+
+        let message_block_len = KEY_SIZE - BLOCK_SIZE;
+        let num_zeros =
+            (message_block_len - (message.len() + 9) % message_block_len) % message_block_len;
+        let bit_len = (message.len() as u64).wrapping_mul(8);
+        let mut padded_message = message
+            .iter()
+            .copied()
+            .chain([0x80])
+            .chain((0..num_zeros).map(|_| 0))
+            .chain(bit_len.to_be_bytes());
+
+        let c = <[u8; BLOCK_SIZE]>::from_le_slice(&[0x01]);
+        let mut g: [u8; BLOCK_SIZE] = join_words([Self::P_W; 2]);
+        let mut h: [u8; BLOCK_SIZE] = join_words([Self::Q_W; 2]);
+
+        let mut key = [0; KEY_SIZE];
+        loop {
+            key[..BLOCK_SIZE].copy_from_slice(&h);
+            let mut message_block_filled = false;
+            for (byte, message_byte) in key[BLOCK_SIZE..].iter_mut().zip(&mut padded_message) {
+                *byte = message_byte;
+                message_block_filled = true;
+            }
+            // The padded message length is a multiple of the message block length.
+            if !message_block_filled {
+                break;
+            }
+
+            let cipher = Self::new(key);
+            let g_c = g.bitxor(c);
+            h = cipher.encrypt(g_c).bitxor(g_c);
+            g = cipher.encrypt(g).bitxor(g);
+        }
+
+        let mut digest = [0; OUTPUT_SIZE];
+        digest[..BLOCK_SIZE].copy_from_slice(&g);
+        digest[BLOCK_SIZE..].copy_from_slice(&h);
+        digest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Expected values are generated by `python3 scripts/reference_vectors.py hash`.
+
+    #[test]
+    fn hirose_hash_rc_32_12_16_empty() {
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::hirose_hash::<16>(&[]),
+            [
+                0x29, 0x5E, 0xE1, 0x8E, 0x33, 0x86, 0x9C, 0xAA, 0x15, 0x8D, 0xB8, 0x23, 0x1C, 0xAC,
+                0x1C, 0x9C
+            ]
+        );
+    }
+
+    #[test]
+    fn hirose_hash_rc_32_12_16_abc() {
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::hirose_hash::<16>(b"abc"),
+            [
+                0x0A, 0x49, 0xF5, 0x05, 0x6F, 0x0D, 0x01, 0x92, 0xAC, 0x75, 0xEC, 0x3F, 0x96, 0x0C,
+                0xC1, 0xAF
+            ]
+        );
+    }
+
+    #[test]
+    fn hirose_hash_rc_32_12_16_multiple_blocks() {
+        let message = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13,
+        ];
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::hirose_hash::<16>(&message),
+            [
+                0x42, 0x10, 0xA6, 0xB0, 0x9F, 0xA2, 0xD4, 0xF8, 0xF6, 0x82, 0xBC, 0xE4, 0xF9, 0xDD,
+                0x78, 0xD4
+            ]
+        );
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn hirose_hash_rc_16_12_8_empty() {
+        assert_eq!(
+            RC5::<16, 12, 8, 2, 4, 26, 4>::hirose_hash::<8>(&[]),
+            [0xD5, 0x77, 0xE8, 0xFF, 0x86, 0x8A, 0x1D, 0x00]
+        );
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    #[should_panic]
+    fn hirose_hash_key_not_larger_than_block() {
+        RC5::<32, 12, 8, 4, 8, 26, 2>::hirose_hash::<16>(&[]);
+    }
+}
@@ -0,0 +1,126 @@
+use core::fmt::{self, Write};
+
+use crate::RC5;
+
+/// A known-answer test vector for a single RC5 block.
+///
+/// Vectors serialize to the following JSON schema using [`KnownAnswerTest::write_json`]:
+///
+/// ```json
+/// {
+///   "algorithm": "RC5",
+///   "parameters": { "w": 32, "r": 12, "b": 16 },
+///   "mode": "ECB",
+///   "iv": null,
+///   "key": "<hex>",
+///   "pt": "<hex>",
+///   "ct": "<hex>"
+/// }
+/// ```
+///
+/// `w`, `r` and `b` are the word bit size, number of rounds and key size in bytes. Byte strings
+/// are encoded as lowercase hex. Since only single blocks are encrypted, `mode` is always `ECB` and
+/// `iv` is always `null`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KnownAnswerTest<const KEY_SIZE: usize, const BLOCK_SIZE: usize> {
+    pub word_bit_size: usize,
+    pub rounds: usize,
+    pub key: [u8; KEY_SIZE],
+    pub plaintext: [u8; BLOCK_SIZE],
+    pub ciphertext: [u8; BLOCK_SIZE],
+}
+
+impl<const KEY_SIZE: usize, const BLOCK_SIZE: usize> KnownAnswerTest<KEY_SIZE, BLOCK_SIZE> {
+    /// Generates a known-answer test vector by encrypting the plaintext block under the key.
+    pub fn generate<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const WORD_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >(
+        key: [u8; KEY_SIZE],
+        plaintext: [u8; BLOCK_SIZE],
+    ) -> Self {
+        let rc5 = RC5::<
+            WORD_BIT_SIZE,
+            ROUNDS,
+            KEY_SIZE,
+            WORD_SIZE,
+            BLOCK_SIZE,
+            EXPANDED_KEY_TABLE_LEN,
+            KEY_AS_WORDS_LEN,
+        >::new(key);
+
+        Self {
+            word_bit_size: WORD_BIT_SIZE,
+            rounds: ROUNDS,
+            key,
+            plaintext,
+            ciphertext: rc5.encrypt(plaintext),
+        }
+    }
+
+    /// Writes the vector as a single line of JSON.
+    pub fn write_json<W: Write>(&self, output: &mut W) -> fmt::Result {
+        write!(
+            output,
+            r#"{{"algorithm":"RC5","parameters":{{"w":{},"r":{},"b":{}}},"mode":"ECB","iv":null,"#,
+            self.word_bit_size, self.rounds, KEY_SIZE,
+        )?;
+        output.write_str(r#""key":""#)?;
+        write_hex(output, &self.key)?;
+        output.write_str(r#"","pt":""#)?;
+        write_hex(output, &self.plaintext)?;
+        output.write_str(r#"","ct":""#)?;
+        write_hex(output, &self.ciphertext)?;
+        output.write_str(r#""}"#)
+    }
+}
+
+fn write_hex<W: Write>(output: &mut W, bytes: &[u8]) -> fmt::Result {
+    for byte in bytes {
+        write!(output, "{byte:02x}")?;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+mod tests {
+    extern crate std;
+
+    use std::string::String;
+
+    use super::*;
+
+    #[test]
+    fn generate_rc_8_12_4() {
+        let kat =
+            KnownAnswerTest::generate::<8, 12, 1, 26, 4>([0x00, 0x01, 0x02, 0x03], [0x00, 0x01]);
+        assert_eq!(kat.ciphertext, [0x21, 0x2A]);
+    }
+
+    #[test]
+    fn write_json_rc_8_12_4() {
+        let kat =
+            KnownAnswerTest::generate::<8, 12, 1, 26, 4>([0x00, 0x01, 0x02, 0x03], [0x00, 0x01]);
+        let mut json = String::new();
+        kat.write_json(&mut json).unwrap();
+        assert_eq!(
+            json,
+            r#"{"algorithm":"RC5","parameters":{"w":8,"r":12,"b":4},"mode":"ECB","iv":null,"key":"00010203","pt":"0001","ct":"212a"}"#
+        );
+    }
+
+    #[test]
+    fn write_json_rc_24_4_0() {
+        let kat =
+            KnownAnswerTest::generate::<24, 4, 3, 10, 1>([], [0x00, 0x01, 0x02, 0x03, 0x04, 0x05]);
+        let mut json = String::new();
+        kat.write_json(&mut json).unwrap();
+        assert_eq!(
+            json,
+            r#"{"algorithm":"RC5","parameters":{"w":24,"r":4,"b":0},"mode":"ECB","iv":null,"key":"","pt":"000102030405","ct":"89cbdcc9525a"}"#
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{
+    key_schedule::{KeyMixer, MIXING_ROTATION},
+    RC5,
+};
+
+/// Performs the RC5 key expansion in resumable chunks.
+///
+/// The expansion of extreme parameter sets, e.g. 255 rounds and a 255 byte key, can take long
+/// enough on slow microcontrollers to trip a watchdog. This type splits the key mixing into
+/// iterations that can be performed a few at a time, with other work such as feeding the watchdog
+/// in between. The resulting cipher is the same as the one created by [`RC5::new`].
+///
+/// Example usage:
+/// ```
+/// use rc5::{KeyExpansion, RC5};
+///
+/// let key = [
+///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+/// ];
+///
+/// // RC5-32/12/16
+/// let mut expansion = KeyExpansion::<32, 12, 16, 4, 8, 26, 4>::new(key);
+/// while !expansion.step(16) {
+///     // Feed the watchdog.
+/// }
+///
+/// let rc5 = expansion.finish();
+/// assert_eq!(
+///     rc5.encrypt([0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77]),
+///     [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E]
+/// );
+/// ```
+pub struct KeyExpansion<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    key_mixer: KeyMixer<WORD_SIZE, EXPANDED_KEY_TABLE_LEN, KEY_AS_WORDS_LEN>,
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    KeyExpansion<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Starts the key expansion. Only the cheap initialization of the expanded key table is done
+    /// up front.
+    pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        Self {
+            key_mixer: KeyMixer::new(
+                key,
+                RC5::<
+                    WORD_BIT_SIZE,
+                    ROUNDS,
+                    KEY_SIZE,
+                    WORD_SIZE,
+                    BLOCK_SIZE,
+                    EXPANDED_KEY_TABLE_LEN,
+                    KEY_AS_WORDS_LEN,
+                >::P_W,
+                RC5::<
+                    WORD_BIT_SIZE,
+                    ROUNDS,
+                    KEY_SIZE,
+                    WORD_SIZE,
+                    BLOCK_SIZE,
+                    EXPANDED_KEY_TABLE_LEN,
+                    KEY_AS_WORDS_LEN,
+                >::Q_W,
+                MIXING_ROTATION,
+            ),
+        }
+    }
+
+    /// Performs up to `max_iterations` of the remaining key mixing iterations, returning whether
+    /// the expansion is complete.
+    pub fn step(&mut self, max_iterations: usize) -> bool {
+        self.key_mixer.mix(max_iterations);
+        self.is_complete()
+    }
+
+    /// Returns whether all key mixing iterations have been performed.
+    pub fn is_complete(&self) -> bool {
+        self.key_mixer.num_mixed == Self::num_iterations()
+    }
+
+    /// Returns the number of key mixing iterations performed so far.
+    pub fn progress(&self) -> usize {
+        self.key_mixer.num_mixed
+    }
+
+    /// Returns the total number of key mixing iterations, i.e. 3 * max(2 * (ROUNDS + 1), c) where
+    /// c is the number of words the key occupies.
+    pub fn num_iterations() -> usize {
+        KeyMixer::<WORD_SIZE, EXPANDED_KEY_TABLE_LEN, KEY_AS_WORDS_LEN>::LEN
+    }
+
+    /// Performs the remaining key mixing iterations in chunks of `chunk_len` iterations, calling
+    /// `on_progress` with the number of iterations performed so far and the total after each
+    /// chunk, and returns the cipher.
+    ///
+    /// Panics if `chunk_len` is 0.
+    pub fn finish_with_progress(
+        mut self,
+        chunk_len: usize,
+        mut on_progress: impl FnMut(usize, usize),
+    ) -> RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    > {
+        assert!(chunk_len > 0, "Chunk length must not be 0.");
+
+        while !self.step(chunk_len) {
+            on_progress(self.progress(), Self::num_iterations());
+        }
+        on_progress(self.progress(), Self::num_iterations());
+
+        self.finish()
+    }
+
+    /// Performs the remaining key mixing iterations at once and returns the cipher.
+    pub fn finish(
+        mut self,
+    ) -> RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    > {
+        self.key_mixer.mix(usize::MAX);
+        RC5::from_expanded_key_table(self.key_mixer.expanded_key_table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rc_32_12_16_key_expansion_in_chunks() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+
+        for (chunk_len, expected_num_steps) in [(1, 78), (7, 12), (78, 1), (1000, 1)] {
+            let mut expansion = KeyExpansion::<32, 12, 16, 4, 8, 26, 4>::new(key);
+            let mut num_steps = 1;
+            while !expansion.step(chunk_len) {
+                num_steps += 1;
+            }
+
+            assert_eq!(num_steps, expected_num_steps);
+            assert!(expansion.finish() == RC5::new(key));
+        }
+    }
+
+    #[test]
+    fn rc_32_255_255_key_expansion_with_progress() {
+        let key = [0xA5; 255];
+        let mut reported = [0; 2];
+        let mut num_reports = 0;
+
+        let rc5 = KeyExpansion::<32, 255, 255, 4, 8, 512, 64>::new(key).finish_with_progress(
+            500,
+            |progress, total| {
+                assert!(progress > reported[0]);
+                reported = [progress, total];
+                num_reports += 1;
+            },
+        );
+
+        assert_eq!(reported, [1536, 1536]);
+        assert_eq!(num_reports, 4);
+        assert!(rc5 == RC5::new(key));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn key_expansion_finish_early() {
+        let mut expansion = KeyExpansion::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        assert!(!expansion.step(10));
+        assert_eq!(expansion.progress(), 10);
+
+        assert_eq!(expansion.finish().encrypt([0x00, 0x01]), [0x21, 0x2A]);
+    }
+}
@@ -0,0 +1,181 @@
+#[cfg(feature = "strict")]
+use crate::rc5::STRICT_MIN_KEY_SIZE;
+use crate::{cmac, error::Error, RC5};
+
+/// How [`RC5::new_from_slice`] treats keys whose length differs from `KEY_SIZE`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyLengthPolicy {
+    /// Rejects keys that are not exactly `KEY_SIZE` bytes long.
+    #[default]
+    Exact,
+    /// Uses the first `KEY_SIZE` bytes of longer keys, dropping the rest. Shorter keys are
+    /// rejected, as padding them would silently change the key.
+    Truncate,
+    /// Compresses keys of any other length into `KEY_SIZE` bytes with [`RC5::derive_child_key`]
+    /// under the all-zero key, so that every key byte affects the result.
+    Compress,
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Creates the cipher from a key whose length is only known at runtime, handling keys of
+    /// another length than `KEY_SIZE` according to the policy.
+    ///
+    /// Fails with [`Error::InvalidLength`] if the policy rejects the key length, and with
+    /// [`Error::UnsupportedParameters`] if a key needs to be compressed but CMAC is not defined for
+    /// the block size (see [`RC5::derive_child_key`]). With the `strict`
+    /// feature, keys shorter than 16 bytes are rejected with [`Error::WeakKey`] regardless of the
+    /// policy, since compressing them does not add any strength.
+    ///
+    /// Example usage:
+    /// ```
+    /// use rc5::{Error, KeyLengthPolicy, RC5};
+    ///
+    /// let key = [
+    ///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+    ///     0x0F, 0x10,
+    /// ];
+    ///
+    /// // RC5-32/12/16
+    /// let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new_from_slice(&key, KeyLengthPolicy::Exact);
+    /// assert!(matches!(rc5, Err(Error::InvalidLength)));
+    ///
+    /// let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new_from_slice(&key, KeyLengthPolicy::Truncate);
+    /// assert_eq!(
+    ///     rc5.unwrap().encrypt([0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77]),
+    ///     [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E]
+    /// );
+    /// ```
+    pub fn new_from_slice(key: &[u8], policy: KeyLengthPolicy) -> Result<Self, Error> {
+        #[cfg(feature = "strict")]
+        if key.len() < STRICT_MIN_KEY_SIZE {
+            return Err(Error::WeakKey);
+        }
+
+        Ok(Self::new(Self::key_from_slice(key, policy)?))
+    }
+
+    /// Returns the `KEY_SIZE` byte key for the slice according to the policy.
+    pub(crate) fn key_from_slice(
+        key: &[u8],
+        policy: KeyLengthPolicy,
+    ) -> Result<[u8; KEY_SIZE], Error> {
+        let mut output = [0; KEY_SIZE];
+        match policy {
+            _ if key.len() == KEY_SIZE => output.copy_from_slice(key),
+            KeyLengthPolicy::Truncate if key.len() > KEY_SIZE => {
+                output.copy_from_slice(&key[..KEY_SIZE])
+            }
+            KeyLengthPolicy::Compress if !cmac::is_supported(BLOCK_SIZE) => {
+                return Err(Error::UnsupportedParameters)
+            }
+            KeyLengthPolicy::Compress => output = Self::new(output).derive_child_key_unchecked(key),
+            KeyLengthPolicy::Exact | KeyLengthPolicy::Truncate => return Err(Error::InvalidLength),
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+        0x0F,
+    ];
+
+    #[test]
+    fn key_from_slice_exact_length() {
+        for policy in [
+            KeyLengthPolicy::Exact,
+            KeyLengthPolicy::Truncate,
+            KeyLengthPolicy::Compress,
+        ] {
+            assert_eq!(
+                RC5::<32, 12, 16, 4, 8, 26, 4>::key_from_slice(&KEY, policy),
+                Ok(KEY)
+            );
+        }
+    }
+
+    #[test]
+    fn key_from_slice_exact() {
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::key_from_slice(&KEY[..15], KeyLengthPolicy::Exact),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::key_from_slice(&[0x00; 17], KeyLengthPolicy::Exact),
+            Err(Error::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn key_from_slice_truncate() {
+        let mut long_key = [0xFF; 20];
+        long_key[..16].copy_from_slice(&KEY);
+
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::key_from_slice(&long_key, KeyLengthPolicy::Truncate),
+            Ok(KEY)
+        );
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::key_from_slice(&KEY[..15], KeyLengthPolicy::Truncate),
+            Err(Error::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn key_from_slice_compress() {
+        let short_key =
+            RC5::<32, 12, 16, 4, 8, 26, 4>::key_from_slice(&KEY[..15], KeyLengthPolicy::Compress);
+        let long_key =
+            RC5::<32, 12, 16, 4, 8, 26, 4>::key_from_slice(&[0x00; 17], KeyLengthPolicy::Compress);
+
+        assert_eq!(
+            short_key,
+            Ok(RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]).derive_child_key(&KEY[..15]))
+        );
+        assert_eq!(
+            long_key,
+            Ok(RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]).derive_child_key(&[0x00; 17]))
+        );
+        assert_ne!(short_key, long_key);
+    }
+
+    #[test]
+    fn key_from_slice_compress_unsupported_block_size() {
+        assert_eq!(
+            RC5::<24, 12, 16, 3, 6, 26, 6>::key_from_slice(
+                &[0x01, 0x02, 0x03],
+                KeyLengthPolicy::Compress
+            ),
+            Err(Error::UnsupportedParameters)
+        );
+        assert!(matches!(
+            RC5::<24, 12, 16, 3, 6, 26, 6>::new_from_slice(&[0x00; 17], KeyLengthPolicy::Compress),
+            Err(Error::UnsupportedParameters)
+        ));
+        assert!(
+            RC5::<24, 12, 16, 3, 6, 26, 6>::new_from_slice(&KEY, KeyLengthPolicy::Compress).is_ok()
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    consts::{p, q},
+    words::ByteIntegerExt,
+};
+
+/// Number of passes over the expanded key table when mixing in the key.
+pub(crate) const MIXING_PASSES: usize = 3;
+/// Fixed rotation amount applied to the words of the expanded key table when mixing in the key.
+pub(crate) const MIXING_ROTATION: u128 = 3;
+
+/// Expands the key into a table of `EXPANDED_KEY_TABLE_LEN` words as described in the RC5 paper.
+pub(crate) fn expand_key<
+    const WORD_BIT_SIZE: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+>(
+    key: [u8; KEY_SIZE],
+) -> [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN] {
+    expand_key_traced::<WORD_BIT_SIZE, KEY_SIZE, WORD_SIZE, EXPANDED_KEY_TABLE_LEN, KEY_AS_WORDS_LEN>(
+        key,
+        p::<WORD_BIT_SIZE, WORD_SIZE>(),
+        q::<WORD_BIT_SIZE, WORD_SIZE>(),
+        MIXING_ROTATION,
+        |_, _| {},
+    )
+}
+
+/// Expands the key initializing the expanded key table from the magic constants `p` and `q` and
+/// rotating its words by `rotation` while mixing, passing the expanded key table to the trace
+/// callback after initialization with the magic constants (pass 0) and after each mixing pass.
+pub(crate) fn expand_key_traced<
+    const WORD_BIT_SIZE: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+>(
+    key: [u8; KEY_SIZE],
+    p: [u8; WORD_SIZE],
+    q: [u8; WORD_SIZE],
+    rotation: u128,
+    mut trace: impl FnMut(usize, &[[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN]),
+) -> [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN] {
+    let mut key_mixer =
+        KeyMixer::<WORD_SIZE, EXPANDED_KEY_TABLE_LEN, KEY_AS_WORDS_LEN>::new(key, p, q, rotation);
+    trace(0, &key_mixer.expanded_key_table);
+
+    for pass in 1..=MIXING_PASSES {
+        key_mixer.mix(KeyMixer::<WORD_SIZE, EXPANDED_KEY_TABLE_LEN, KEY_AS_WORDS_LEN>::PASS_LEN);
+        trace(pass, &key_mixer.expanded_key_table);
+    }
+
+    key_mixer.expanded_key_table
+}
+
+/// Mixes the key into the expanded key table one iteration at a time, so that the expansion can be
+/// interrupted and resumed.
+pub(crate) struct KeyMixer<
+    const WORD_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    pub(crate) expanded_key_table: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN],
+    key_as_words: [[u8; WORD_SIZE]; KEY_AS_WORDS_LEN],
+    rotation: u128,
+    expanded_key_word_idx: usize,
+    key_word_idx: usize,
+    last_expanded_key_word: [u8; WORD_SIZE],
+    last_key_word: [u8; WORD_SIZE],
+    pub(crate) num_mixed: usize,
+}
+
+impl<
+        const WORD_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > KeyMixer<WORD_SIZE, EXPANDED_KEY_TABLE_LEN, KEY_AS_WORDS_LEN>
+{
+    /// Number of mixing iterations per pass.
+    pub(crate) const PASS_LEN: usize = if KEY_AS_WORDS_LEN > EXPANDED_KEY_TABLE_LEN {
+        KEY_AS_WORDS_LEN
+    } else {
+        EXPANDED_KEY_TABLE_LEN
+    };
+    /// Total number of mixing iterations.
+    pub(crate) const LEN: usize = MIXING_PASSES * Self::PASS_LEN;
+
+    /// Converts the key into words and initializes the expanded key table from the magic constants
+    /// `p` and `q`, ready for mixing with words rotated by `rotation`.
+    pub(crate) fn new<const KEY_SIZE: usize>(
+        key: [u8; KEY_SIZE],
+        p: [u8; WORD_SIZE],
+        q: [u8; WORD_SIZE],
+        rotation: u128,
+    ) -> Self {
+        // Convert key from byte array to a word array.
+        let mut key_as_words: [[u8; WORD_SIZE]; KEY_AS_WORDS_LEN] =
+            [[0; WORD_SIZE]; KEY_AS_WORDS_LEN];
+
+        for idx in (0..KEY_SIZE).rev() {
+            let key_word = &mut key_as_words[idx / WORD_SIZE];
+            *key_word = key_word
+                .rotate_left(8)
+                .wrapping_add(<[u8; WORD_SIZE]>::from_le_slice(&[key[idx]]));
+        }
+
+        // Create expanded key table.
+        let mut expanded_key_table: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN] =
+            [[0; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN];
+
+        expanded_key_table[0] = p;
+
+        for idx in 1..expanded_key_table.len() {
+            expanded_key_table[idx] = expanded_key_table[idx - 1].wrapping_add(q);
+        }
+
+        Self {
+            expanded_key_table,
+            key_as_words,
+            rotation,
+            expanded_key_word_idx: 0,
+            key_word_idx: 0,
+            last_expanded_key_word: [0; WORD_SIZE],
+            last_key_word: [0; WORD_SIZE],
+            num_mixed: 0,
+        }
+    }
+
+    /// Performs up to `max_iterations` of the remaining mixing iterations.
+    pub(crate) fn mix(&mut self, max_iterations: usize) {
+        let num_iterations = max_iterations.min(Self::LEN - self.num_mixed);
+
+        // Mix the word array and expanded key table.
+        for _ in 0..num_iterations {
+            let expanded_key_word = &mut self.expanded_key_table[self.expanded_key_word_idx];
+            *expanded_key_word = expanded_key_word
+                .wrapping_add(self.last_expanded_key_word)
+                .wrapping_add(self.last_key_word)
+                .rotate_left(self.rotation);
+            self.last_expanded_key_word = *expanded_key_word;
+
+            let key_word = &mut self.key_as_words[self.key_word_idx];
+            *key_word = key_word
+                .wrapping_add(self.last_expanded_key_word)
+                .wrapping_add(self.last_key_word)
+                .rotate_left(u128::from_le_bytes(<[u8; 16]>::from_le_slice(
+                    &self.last_expanded_key_word.wrapping_add(self.last_key_word),
+                )));
+            self.last_key_word = *key_word;
+
+            self.expanded_key_word_idx =
+                (self.expanded_key_word_idx + 1) % self.expanded_key_table.len();
+            self.key_word_idx = (self.key_word_idx + 1) % self.key_as_words.len();
+        }
+
+        self.num_mixed += num_iterations;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_key_32_16() {
+        let expanded_key_table = expand_key::<32, 16, 4, 26, 4>([0x00; 16]);
+        assert_eq!(expanded_key_table[0], [0xC8, 0xD8, 0xBB, 0x9B]);
+        assert_eq!(expanded_key_table[1], [0xFB, 0xF7, 0x37, 0x1A]);
+        assert_eq!(expanded_key_table[25], [0x80, 0x63, 0x04, 0x65]);
+    }
+
+    #[test]
+    fn expand_key_traced_32_16() {
+        let mut num_passes = 0;
+        let expanded_key_table = expand_key_traced::<32, 16, 4, 26, 4>(
+            [0x00; 16],
+            p::<32, 4>(),
+            q::<32, 4>(),
+            MIXING_ROTATION,
+            |pass, table| {
+                assert_eq!(pass, num_passes);
+                if pass == 0 {
+                    // Before mixing, the table only consists of the magic constants.
+                    assert_eq!(table[0], p::<32, 4>());
+                    assert_eq!(table[1], p::<32, 4>().wrapping_add(q::<32, 4>()));
+                }
+                num_passes += 1;
+            },
+        );
+
+        assert_eq!(num_passes, MIXING_PASSES + 1);
+        assert_eq!(
+            expanded_key_table,
+            expand_key::<32, 16, 4, 26, 4>([0x00; 16])
+        );
+    }
+
+    #[test]
+    fn expand_key_8_0() {
+        assert_eq!(
+            expand_key::<8, 0, 1, 4, 1>([]),
+            [[0x86], [0xD5], [0x8E], [0x81]]
+        );
+    }
+}
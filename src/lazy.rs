@@ -0,0 +1,150 @@
+use crate::RC5;
+
+/// Provides RC5 with the key expansion deferred until the first block is encrypted or decrypted.
+///
+/// Until then only the raw key is stored, which makes constructing ciphers that may never be used
+/// cheap. Since the first call mutates the cipher, encryption and decryption take `&mut self`.
+///
+/// Example usage:
+/// ```
+/// use rc5::LazyRC5;
+///
+/// let key = [
+///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+/// ];
+///
+/// // RC5-32/12/16
+/// let mut rc5 = LazyRC5::<32, 12, 16, 4, 8, 26, 4>::new(key);
+/// assert!(!rc5.is_expanded());
+///
+/// assert_eq!(
+///     rc5.encrypt([0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77]),
+///     [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E]
+/// );
+/// assert!(rc5.is_expanded());
+/// ```
+pub struct LazyRC5<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    state: State<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >,
+}
+
+enum State<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    Key([u8; KEY_SIZE]),
+    Expanded(
+        RC5<
+            WORD_BIT_SIZE,
+            ROUNDS,
+            KEY_SIZE,
+            WORD_SIZE,
+            BLOCK_SIZE,
+            EXPANDED_KEY_TABLE_LEN,
+            KEY_AS_WORDS_LEN,
+        >,
+    ),
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    LazyRC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        Self {
+            state: State::Key(key),
+        }
+    }
+
+    /// Returns whether the key has been expanded.
+    pub fn is_expanded(&self) -> bool {
+        matches!(self.state, State::Expanded(_))
+    }
+
+    /// Encrypts the plaintext block returning ciphertext block, expanding the key first if needed.
+    pub fn encrypt(&mut self, plaintext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        self.cipher().encrypt(plaintext)
+    }
+
+    /// Decrypts the ciphertext block returning plaintext block, expanding the key first if needed.
+    pub fn decrypt(&mut self, ciphertext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        self.cipher().decrypt(ciphertext)
+    }
+
+    fn cipher(
+        &mut self,
+    ) -> &RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    > {
+        if let State::Key(key) = self.state {
+            self.state = State::Expanded(RC5::new(key));
+        }
+
+        match &self.state {
+            State::Expanded(rc5) => rc5,
+            State::Key(_) => unreachable!("Key is expanded above."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lazy_rc_32_12_16_encrypt_decrypt() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+
+        let mut rc5 = LazyRC5::<32, 12, 16, 4, 8, 26, 4>::new(key);
+        assert!(!rc5.is_expanded());
+        assert_eq!(rc5.decrypt(ciphertext), plaintext);
+        assert!(rc5.is_expanded());
+        assert_eq!(rc5.encrypt(plaintext), ciphertext);
+    }
+}
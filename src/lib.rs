@@ -5,14 +5,90 @@
 //! it is not making use of hardware intrinsics when dealing with word sizes that match u32, u64,
 //! etc.
 //!
+//! The closely related RC6 encryption algorithm is provided as well, sharing the same
+//! parameterization and key expansion.
+//!
+//! The fixed-size little-endian integer operations the ciphers are built on are exposed in the
+//! [`words`] module for reuse in other `no_std` code.
+//!
+//! Ready-made types for the parameter sets of the RC5 and RC6 test vectors draft are provided in the
+//! [`variants`] module.
+//!
 //! The library makes heavy use of const generics. However, since const generics do not support
 //! arithmetics in const context on stable Rust, the API is unnecessarily verbose and error prone.
 //! This can be improved in the future once const generics gain more power.
 
 #![no_std]
 
-mod bytes;
+#[cfg(feature = "analysis")]
+mod analysis;
+mod arx;
+#[cfg(feature = "attack")]
+mod attack;
+mod batch;
+mod cache;
+mod cfb;
+mod cmac;
 mod consts;
+mod decryptor;
+#[cfg(feature = "differential")]
+mod differential;
+mod diversify;
+mod encryptor;
+mod error;
+#[cfg(feature = "generic-array")]
+mod generic;
+mod hash;
+mod kat;
+mod key_expansion;
+mod key_length;
+mod key_schedule;
+mod lazy;
+mod license;
+mod masked;
+mod ofb;
+mod pbkdf2;
+mod permute;
+mod presets;
+mod ratchet;
 mod rc5;
+mod rc6;
+#[cfg(feature = "research")]
+mod research;
+#[cfg(feature = "rand_core")]
+mod rng;
+#[cfg(feature = "rayon")]
+mod search;
+mod triple;
+pub mod variants;
+mod wipe;
+pub mod words;
 
-pub use crate::rc5::*;
+#[cfg(feature = "analysis")]
+pub use crate::analysis::{HalfRoundRotations, RotationStats};
+#[cfg(feature = "differential")]
+pub use crate::differential::Characteristic;
+#[cfg(feature = "research")]
+pub use crate::research::{RC5Xor, RC5P};
+#[cfg(feature = "rand_core")]
+pub use crate::rng::{RC5Rng, RC5RngCore};
+pub use crate::{
+    cache::{CacheStats, KeyScheduleCache},
+    cfb::RC5Cfb1,
+    consts::MagicConstant,
+    decryptor::RC5Decryptor,
+    encryptor::RC5Encryptor,
+    error::Error,
+    kat::KnownAnswerTest,
+    key_expansion::KeyExpansion,
+    key_length::KeyLengthPolicy,
+    lazy::LazyRC5,
+    license::{License, LicenseCode, LICENSE_CODE_LEN},
+    masked::MaskedRC5,
+    ofb::RC5Ofb8,
+    presets::{RC5Conservative, RC5Fast, RC5Standard},
+    ratchet::Ratchet,
+    rc5::*,
+    rc6::*,
+    triple::TripleRC5,
+};
@@ -0,0 +1,236 @@
+use core::fmt;
+
+use crate::{error::Error, RC5};
+
+/// Crockford's Base32 alphabet, which leaves out I, L, O and U to avoid confusing symbols.
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+/// Crockford's check symbols, the Base32 alphabet extended to 37 symbols.
+const CHECK_ALPHABET: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+/// Number of Base32 symbols encoding the 64-bit block.
+const BLOCK_SYMBOLS: usize = 13;
+/// Number of symbols between the hyphens of a license code.
+const GROUP_LEN: usize = 5;
+
+/// Length of a license code in bytes: 13 Base32 symbols and a check symbol in groups of 5 separated
+/// by hyphens, e.g. `CHMM0-G9Z4E-40SP`.
+pub const LICENSE_CODE_LEN: usize = 16;
+
+/// The contents of a license code issued by [`RC5::issue_license`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct License {
+    /// Identifies the licensee, e.g. a customer or serial number.
+    pub id: u32,
+    /// Application-defined flags, e.g. the licensed product edition or features.
+    pub flags: u16,
+}
+
+/// A human-typable license code produced by [`RC5::issue_license`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LicenseCode([u8; LICENSE_CODE_LEN]);
+
+impl LicenseCode {
+    /// Returns the license code as a string.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.0).expect("License code is ASCII.")
+    }
+}
+
+impl fmt::Display for LicenseCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > RC5<32, ROUNDS, KEY_SIZE, 4, 8, EXPANDED_KEY_TABLE_LEN, KEY_AS_WORDS_LEN>
+{
+    /// Issues an offline license code for the license.
+    ///
+    /// The ID, the flags and 16 zero bits of redundancy are encrypted as a single block, which is
+    /// encoded with Crockford's Base32 followed by its mod 37 check symbol. The check symbol catches
+    /// typos, while the redundancy makes a tampered or made up code pass
+    /// [`RC5::verify_license`] with a probability of only 2^-16.
+    ///
+    /// Since the same key verifies and issues codes, anyone able to extract the key from the
+    /// verifying application can issue codes as well.
+    ///
+    /// Example usage:
+    /// ```
+    /// use rc5::{License, RC5};
+    ///
+    /// // RC5-32/12/16
+    /// let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]);
+    ///
+    /// let license = License { id: 42, flags: 0b11 };
+    /// let code = rc5.issue_license(license);
+    /// assert_eq!(code.as_str(), "CHMM0-G9Z4E-40SP");
+    /// assert_eq!(rc5.verify_license("chmm0-g9z4e-40sp"), Ok(license));
+    /// ```
+    pub fn issue_license(&self, license: License) -> LicenseCode {
+        let mut block = [0; 8];
+        block[..4].copy_from_slice(&license.id.to_le_bytes());
+        block[4..6].copy_from_slice(&license.flags.to_le_bytes());
+        let value = u64::from_le_bytes(self.encrypt(block));
+
+        let mut symbols = [0; BLOCK_SYMBOLS + 1];
+        for (idx, symbol) in symbols[..BLOCK_SYMBOLS].iter_mut().rev().enumerate() {
+            *symbol = ALPHABET[(value >> (5 * idx)) as usize & 0x1F];
+        }
+        symbols[BLOCK_SYMBOLS] = CHECK_ALPHABET[(value % 37) as usize];
+
+        let mut code = [b'-'; LICENSE_CODE_LEN];
+        for (chunk, group) in code
+            .chunks_mut(GROUP_LEN + 1)
+            .zip(symbols.chunks(GROUP_LEN))
+        {
+            chunk[..group.len()].copy_from_slice(group);
+        }
+        LicenseCode(code)
+    }
+
+    /// Verifies a license code issued by [`RC5::issue_license`] and returns its contents.
+    ///
+    /// Decoding is case-insensitive, ignores hyphens and reads O as 0 and I and L as 1, as
+    /// specified by Crockford's Base32.
+    ///
+    /// Fails with [`Error::InvalidLength`] if the code does not consist of 14 symbols,
+    /// [`Error::InvalidEncoding`] if it contains other symbols, [`Error::InvalidChecksum`] if the
+    /// check symbol does not match, e.g. because of a typo, and [`Error::InvalidLicense`] if the
+    /// code was not issued under this key.
+    pub fn verify_license(&self, code: &str) -> Result<License, Error> {
+        let mut symbols = code.bytes().filter(|&symbol| symbol != b'-');
+
+        let mut value: u64 = 0;
+        for _ in 0..BLOCK_SYMBOLS {
+            let symbol = symbols.next().ok_or(Error::InvalidLength)?;
+            let digit = decode_symbol(symbol, ALPHABET).ok_or(Error::InvalidEncoding)?;
+            if value >> 59 != 0 {
+                return Err(Error::InvalidEncoding);
+            }
+            value = value << 5 | digit as u64;
+        }
+
+        let check_symbol = symbols.next().ok_or(Error::InvalidLength)?;
+        if symbols.next().is_some() {
+            return Err(Error::InvalidLength);
+        }
+        let check = decode_symbol(check_symbol, CHECK_ALPHABET).ok_or(Error::InvalidEncoding)?;
+        if check as u64 != value % 37 {
+            return Err(Error::InvalidChecksum);
+        }
+
+        let block = self.decrypt(value.to_le_bytes());
+        if block[6..] != [0, 0] {
+            return Err(Error::InvalidLicense);
+        }
+
+        Ok(License {
+            id: u32::from_le_bytes([block[0], block[1], block[2], block[3]]),
+            flags: u16::from_le_bytes([block[4], block[5]]),
+        })
+    }
+}
+
+fn decode_symbol(symbol: u8, alphabet: &[u8]) -> Option<u8> {
+    let symbol = match symbol.to_ascii_uppercase() {
+        b'O' => b'0',
+        b'I' | b'L' => b'1',
+        symbol => symbol,
+    };
+    alphabet
+        .iter()
+        .position(|&candidate| candidate == symbol)
+        .map(|digit| digit as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+        0x0F,
+    ];
+
+    // Expected values are generated by `python3 scripts/reference_vectors.py license`.
+
+    #[test]
+    fn rc_32_12_16_issue_verify_license() {
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+
+        for (id, flags, code) in [
+            (0, 0, "A5D8K-17PPE-QXGW"),
+            (1, 0, "1KP81-9RCR1-JGV8"),
+            (0xDEADBEEF, 0xFFFF, "DSS59-D6KG3-HNZ*"),
+            (123456, 0x0102, "7TNVB-5J5SK-5N9F"),
+        ] {
+            let license = License { id, flags };
+            assert_eq!(rc5.issue_license(license).as_str(), code);
+            assert_eq!(rc5.verify_license(code), Ok(license));
+        }
+    }
+
+    #[test]
+    fn rc_32_12_16_verify_license_lenient_decoding() {
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+        let license = License { id: 0, flags: 0 };
+
+        assert_eq!(rc5.verify_license("a5d8k-i7ppe-qxgw"), Ok(license));
+        assert_eq!(rc5.verify_license("A5D8KL7PPEQXGW"), Ok(license));
+    }
+
+    #[test]
+    fn rc_32_12_16_verify_license_typo() {
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+
+        assert_eq!(
+            rc5.verify_license("A5D8K-17PPE-QXGX"),
+            Err(Error::InvalidChecksum)
+        );
+        assert_eq!(
+            rc5.verify_license("A5D8K-71PPE-QXGW"),
+            Err(Error::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn rc_32_12_16_verify_license_tampered() {
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+
+        // The last bit of the encrypted block is flipped and the check symbol recomputed.
+        assert_eq!(
+            rc5.verify_license("1KP81-9RCR1-JGT7"),
+            Err(Error::InvalidLicense)
+        );
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]).verify_license("1KP81-9RCR1-JGV8"),
+            Err(Error::InvalidLicense)
+        );
+    }
+
+    #[test]
+    fn verify_license_malformed() {
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+
+        assert_eq!(
+            rc5.verify_license("A5D8K-17PPE-QXG"),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            rc5.verify_license("A5D8K-17PPE-QXGW0"),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            rc5.verify_license("A5D8K-17PPU-QXGW"),
+            Err(Error::InvalidEncoding)
+        );
+        assert_eq!(
+            rc5.verify_license("G5D8K-17PPE-QXGW"),
+            Err(Error::InvalidEncoding)
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{arx::rotation_amount, wipe::wipe, words::ByteIntegerExt, RC5};
+
+/// Provides RC5 with the expanded key table kept XOR-masked in memory.
+///
+/// Every word of the expanded key table is stored XORed with a mask word and only unmasked on the
+/// fly while processing a block, so that a memory dump of a long-running process holding many
+/// ciphers does not contain the expanded key tables in the clear. The masks should be random and
+/// can be refreshed at any time with [`MaskedRC5::remask`] without unmasking the table.
+///
+/// This raises the bar for memory-scraping attacks but does not prevent them:
+/// - the masks live in memory next to the masked table, so a dump containing both reveals it;
+/// - unmasked words briefly reside in registers or on the stack while processing a block;
+/// - [`MaskedRC5::new`] wipes the key and the unmasked table it expands, but copies made by the
+///   compiler, e.g. when moving values between stack frames, cannot be wiped reliably;
+/// - [`RC5::masked`] leaves the cipher it is called on, and thus its unmasked table, untouched;
+/// - the masked table and the masks are wiped when the cipher is dropped, but copies left behind
+///   by moving it are not.
+///
+/// Create one with [`MaskedRC5::new`] or [`RC5::masked`].
+///
+/// Example usage:
+/// ```
+/// use rc5::MaskedRC5;
+///
+/// // Use random masks, e.g. from the hardware random number generator, in practice.
+/// let mask = [[0xA5; 4]; 26];
+/// let key = [
+///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+/// ];
+/// let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+/// let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+///
+/// // RC5-32/12/16
+/// let rc5 = MaskedRC5::<32, 12, 16, 4, 8, 26, 4>::new(key, mask);
+///
+/// assert_eq!(rc5.encrypt(plaintext), ciphertext);
+/// assert_eq!(rc5.decrypt(ciphertext), plaintext);
+/// ```
+pub struct MaskedRC5<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    // The wrapped cipher holds the masked expanded key table and must not be used directly.
+    masked: RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >,
+    mask: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN],
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Returns a copy of the cipher with the expanded key table XOR-masked with `mask`.
+    ///
+    /// This cipher keeps its unmasked expanded key table. Prefer [`MaskedRC5::new`], which wipes
+    /// the unmasked table after masking it.
+    pub fn masked(
+        &self,
+        mask: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN],
+    ) -> MaskedRC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    > {
+        // Masks the words directly into the new table, so that no unmasked copy is made.
+        let mut expanded_key_table = mask;
+        for (word, key_word) in expanded_key_table
+            .iter_mut()
+            .zip(self.expanded_key_table.iter())
+        {
+            *word = word.bitxor(*key_word);
+        }
+        MaskedRC5 {
+            masked: self.with_expanded_key_table(expanded_key_table),
+            mask,
+        }
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    MaskedRC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Expands the key and masks the expanded key table, wiping the key and the unmasked table
+    /// afterwards.
+    pub fn new(mut key: [u8; KEY_SIZE], mask: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN]) -> Self {
+        let mut rc5 = RC5::new(key);
+        wipe(&mut key);
+
+        let masked = rc5.masked(mask);
+        rc5.wipe();
+        masked
+    }
+
+    /// Replaces the masks of the expanded key table without unmasking it.
+    pub fn remask(&mut self, mask: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN]) {
+        for ((word, old_mask), new_mask) in self
+            .masked
+            .expanded_key_table
+            .iter_mut()
+            .zip(self.mask.iter())
+            .zip(mask.iter())
+        {
+            *word = word.bitxor(old_mask.bitxor(*new_mask));
+        }
+        self.mask = mask;
+    }
+
+    /// Encrypts the plaintext block returning ciphertext block.
+    pub fn encrypt(&self, plaintext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let [mut a, mut b] = self.masked.load_block(plaintext);
+
+        a = a.wrapping_add(self.key_word(0));
+        b = b.wrapping_add(self.key_word(1));
+
+        for idx in 1..=ROUNDS {
+            a = a
+                .bitxor(b)
+                .rotate_left(rotation_amount(b))
+                .wrapping_add(self.key_word(2 * idx));
+            b = b
+                .bitxor(a)
+                .rotate_left(rotation_amount(a))
+                .wrapping_add(self.key_word(2 * idx + 1));
+        }
+
+        self.masked.store_block([a, b])
+    }
+
+    /// Decrypts the ciphertext block returning plaintext block.
+    pub fn decrypt(&self, ciphertext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let [mut a, mut b] = self.masked.load_block(ciphertext);
+
+        for idx in (1..=ROUNDS).rev() {
+            b = b
+                .wrapping_sub(self.key_word(2 * idx + 1))
+                .rotate_right(rotation_amount(a))
+                .bitxor(a);
+            a = a
+                .wrapping_sub(self.key_word(2 * idx))
+                .rotate_right(rotation_amount(b))
+                .bitxor(b);
+        }
+
+        b = b.wrapping_sub(self.key_word(1));
+        a = a.wrapping_sub(self.key_word(0));
+
+        self.masked.store_block([a, b])
+    }
+
+    /// Unmasks the word of the expanded key table at the index.
+    fn key_word(&self, idx: usize) -> [u8; WORD_SIZE] {
+        self.masked.expanded_key_table[idx].bitxor(self.mask[idx])
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > Drop
+    for MaskedRC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    fn drop(&mut self) {
+        self.masked.wipe();
+        wipe(self.mask.iter_mut().flatten());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::ManuallyDrop;
+
+    use super::*;
+
+    #[test]
+    fn rc_32_12_16_masked() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+
+        let mut mask = [[0; 4]; 26];
+        for (idx, word) in mask.iter_mut().enumerate() {
+            *word = [idx as u8, 0x5A, 0xC3, !(idx as u8)];
+        }
+
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(key);
+        let masked = MaskedRC5::<32, 12, 16, 4, 8, 26, 4>::new(key, mask);
+        assert_ne!(masked.masked.expanded_key_table, rc5.expanded_key_table);
+        assert_eq!(masked.encrypt(plaintext), ciphertext);
+        assert_eq!(masked.decrypt(ciphertext), plaintext);
+    }
+
+    #[test]
+    fn rc_32_12_16_remask() {
+        let key = [0x00; 16];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(key);
+        let mut masked = rc5.masked([[0x11; 4]; 26]);
+        let masked_table = masked.masked.expanded_key_table;
+
+        masked.remask([[0x22; 4]; 26]);
+        assert_ne!(masked.masked.expanded_key_table, masked_table);
+
+        let block = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        assert_eq!(masked.encrypt(block), rc5.encrypt(block));
+        assert_eq!(masked.decrypt(block), rc5.decrypt(block));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_8_12_4_masked_swapped_halves() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]).with_swapped_halves();
+        let masked = rc5.masked([[0xA5]; 26]);
+
+        for block in [[0x00, 0x01], [0x21, 0x2A], [0xFF, 0x00]] {
+            assert_eq!(masked.encrypt(block), rc5.encrypt(block));
+            assert_eq!(masked.decrypt(block), rc5.decrypt(block));
+        }
+    }
+
+    #[test]
+    fn drop_wipes_masked_table_and_masks() {
+        let mut masked = ManuallyDrop::new(MaskedRC5::<32, 12, 16, 4, 8, 26, 4>::new(
+            [0xFF; 16],
+            [[0xA5; 4]; 26],
+        ));
+
+        // SAFETY: The cipher is not used after dropping apart from inspecting its plain byte arrays,
+        // which dropping leaves in place.
+        unsafe { ManuallyDrop::drop(&mut masked) };
+
+        assert_eq!(masked.masked.expanded_key_table, [[0x00; 4]; 26]);
+        assert_eq!(masked.mask, [[0x00; 4]; 26]);
+    }
+}
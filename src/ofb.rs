@@ -0,0 +1,127 @@
+use crate::RC5;
+
+/// Provides RC5 in output feedback mode with 8-bit feedback (OFB-8), e.g. for compatibility with
+/// devices that encrypt byte by byte.
+///
+/// Every byte is XORed with the most significant (first) byte of the encrypted shift register,
+/// after which that keystream byte is shifted into the register from the right. The register starts
+/// out as the IV. Unlike full-block OFB, each byte costs one block encryption. Since the keystream
+/// does not depend on the data, encryption and decryption are the same operation.
+///
+/// Example usage:
+/// ```
+/// use rc5::RC5Ofb8;
+///
+/// let key = [0x00; 16];
+/// let iv = [0x00; 8];
+///
+/// // RC5-32/12/16
+/// let mut encryptor = RC5Ofb8::<32, 12, 16, 4, 8, 26, 4>::new(key, iv);
+/// let mut decryptor = RC5Ofb8::<32, 12, 16, 4, 8, 26, 4>::new(key, iv);
+///
+/// let mut data = *b"setpoint";
+/// encryptor.apply_keystream(&mut data);
+/// decryptor.apply_keystream(&mut data);
+/// assert_eq!(&data, b"setpoint");
+/// ```
+pub struct RC5Ofb8<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    cipher: RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >,
+    register: [u8; BLOCK_SIZE],
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5Ofb8<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Creates the mode from the key and the IV the shift register starts out as.
+    pub fn new(key: [u8; KEY_SIZE], iv: [u8; BLOCK_SIZE]) -> Self {
+        Self {
+            cipher: RC5::new(key),
+            register: iv,
+        }
+    }
+
+    /// Encrypts or decrypts the data in place by XORing it with the keystream.
+    ///
+    /// The keystream continues where the previous call left off, so data can be processed in
+    /// chunks of any length.
+    pub fn apply_keystream(&mut self, data: &mut [u8]) {
+        for byte in data {
+            let keystream_byte = self.cipher.encrypt(self.register)[0];
+            *byte ^= keystream_byte;
+
+            self.register.copy_within(1.., 0);
+            self.register[BLOCK_SIZE - 1] = keystream_byte;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Expected values are generated by `python3 scripts/reference_vectors.py ofb8`.
+
+    const KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+        0x0F,
+    ];
+    const IV: [u8; 8] = [0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17];
+    const PLAINTEXT: [u8; 12] = *b"valve open 1";
+    const CIPHERTEXT: [u8; 12] = [
+        0x17, 0x8C, 0xDB, 0x48, 0x13, 0x77, 0xCE, 0x30, 0x8C, 0x58, 0xC7, 0xDE,
+    ];
+
+    #[test]
+    fn rc_32_12_16_ofb8_encrypt_decrypt() {
+        let mut data = PLAINTEXT;
+        RC5Ofb8::<32, 12, 16, 4, 8, 26, 4>::new(KEY, IV).apply_keystream(&mut data);
+        assert_eq!(data, CIPHERTEXT);
+
+        RC5Ofb8::<32, 12, 16, 4, 8, 26, 4>::new(KEY, IV).apply_keystream(&mut data);
+        assert_eq!(data, PLAINTEXT);
+    }
+
+    #[test]
+    fn rc_32_12_16_ofb8_in_chunks() {
+        let mut ofb = RC5Ofb8::<32, 12, 16, 4, 8, 26, 4>::new(KEY, IV);
+        let mut data = PLAINTEXT;
+
+        let (head, tail) = data.split_at_mut(5);
+        ofb.apply_keystream(head);
+        ofb.apply_keystream(tail);
+
+        assert_eq!(data, CIPHERTEXT);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{cmac::cmac, words::ByteIntegerExt, KeyLengthPolicy, RC5};
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Derives an `OUTPUT_SIZE` byte key from a password and a salt with PBKDF2 (RFC 8018), using
+    /// CMAC with this cipher as the pseudorandom function instead of HMAC.
+    ///
+    /// CMAC is keyed with the password if it is exactly `KEY_SIZE` bytes long. Otherwise the
+    /// password is first compressed into a key as with [`KeyLengthPolicy::Compress`], like RFC 4615
+    /// does for AES-CMAC-PRF-128.
+    ///
+    /// Panics if `iterations` is 0. Fails to compile under the same conditions as
+    /// [`RC5::derive_child_key`].
+    pub fn derive_key_from_password<const OUTPUT_SIZE: usize>(
+        password: &[u8],
+        salt: &[u8],
+        iterations: u32,
+    ) -> [u8; OUTPUT_SIZE] {
+        let () = Self::CMAC_SUPPORTED;
+        assert!(iterations > 0, "PBKDF2 requires at least one iteration.");
+
+        // Passwords are not keys, so the strict minimum key size does not apply to them.
+        let prf = Self::new(
+            Self::key_from_slice(password, KeyLengthPolicy::Compress)
+                .expect("Compressing accepts keys of any length if CMAC is supported."),
+        );
+        let mac = |parts: &[&[u8]]| cmac(|block| prf.encrypt(block), parts);
+
+        let mut output = [0; OUTPUT_SIZE];
+        for (block_idx, chunk) in (1u32..).zip(output.chunks_mut(BLOCK_SIZE)) {
+            let mut u = mac(&[salt, &block_idx.to_be_bytes()]);
+            let mut t = u;
+            for _ in 1..iterations {
+                u = mac(&[&u]);
+                t = t.bitxor(u);
+            }
+            chunk.copy_from_slice(&t[..chunk.len()]);
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Expected values are generated by `python3 scripts/reference_vectors.py pbkdf2`.
+
+    #[test]
+    fn derive_key_from_password_rc_32_12_16_1_iteration() {
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::derive_key_from_password::<16>(b"password", b"salt", 1),
+            [
+                0xA3, 0x11, 0x9A, 0x8D, 0x1B, 0x96, 0x5C, 0x12, 0x8E, 0x17, 0x44, 0x03, 0xD8, 0x00,
+                0xE2, 0x95
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_key_from_password_rc_32_12_16_2_iterations() {
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::derive_key_from_password::<16>(b"password", b"salt", 2),
+            [
+                0xD7, 0x86, 0x48, 0xE4, 0xDB, 0xDE, 0xD0, 0xBB, 0x8D, 0xBC, 0x53, 0xFF, 0x73, 0x9F,
+                0xD4, 0xAD
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_key_from_password_rc_32_12_16_100_iterations_partial_block() {
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::derive_key_from_password::<20>(
+                b"password",
+                b"salt",
+                100
+            ),
+            [
+                0x1C, 0x5F, 0x0E, 0x3D, 0xC8, 0x7B, 0x2C, 0xF7, 0x04, 0x21, 0x29, 0x99, 0x80, 0x0B,
+                0xF7, 0x98, 0xB6, 0x35, 0x84, 0x6F
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_key_from_password_rc_32_12_16_key_sized_password() {
+        let password = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::derive_key_from_password::<8>(&password, b"salt", 1),
+            [0x39, 0xBC, 0x67, 0x4E, 0xDE, 0x29, 0xF7, 0x4F]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn derive_key_from_password_zero_iterations() {
+        RC5::<32, 12, 16, 4, 8, 26, 4>::derive_key_from_password::<8>(b"password", b"salt", 0);
+    }
+}
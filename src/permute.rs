@@ -0,0 +1,269 @@
+use core::ops::Range;
+
+use crate::RC5;
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Pseudorandomly permutes a value within the range, e.g. to map sequential database IDs to
+    /// non-guessable ones without a lookup table.
+    ///
+    /// The offset of the value within the range is encrypted as a little-endian block, repeatedly
+    /// if needed, until the result falls within the range again (cycle walking). The expected
+    /// number of encryptions is the block domain size divided by the range length, so pick the
+    /// smallest block size that fits the range.
+    ///
+    /// Panics if the value is not within the range, if the block is larger than 8 bytes, if the
+    /// range does not fit in a block or if the range is too small for the block, i.e. shorter than
+    /// 2^-16 of the block domain (e.g. 2^48 values for 64-bit blocks), as cycle walking would take
+    /// more than 2^16 encryptions on average.
+    ///
+    /// Example usage:
+    /// ```
+    /// use rc5::RC5;
+    ///
+    /// let key = [
+    ///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+    ///     0x0F,
+    /// ];
+    ///
+    /// // RC5-8/12/16
+    /// let rc5 = RC5::<8, 12, 16, 1, 2, 26, 16>::new(key);
+    ///
+    /// let id = rc5.permute_in_range(1, 0..1000);
+    /// assert_eq!(id, 963);
+    /// assert_eq!(rc5.inverse_permute_in_range(id, 0..1000), 1);
+    /// ```
+    pub fn permute_in_range(&self, value: u64, range: Range<u64>) -> u64 {
+        cycle_walk(value, range, |block| self.encrypt(block))
+    }
+
+    /// Reverts [`RC5::permute_in_range`] for the same range.
+    pub fn inverse_permute_in_range(&self, value: u64, range: Range<u64>) -> u64 {
+        cycle_walk(value, range, |block| self.decrypt(block))
+    }
+
+    /// Returns a keyed pseudorandom permutation of the indices `0..len`, e.g. for reproducible
+    /// shuffling of data in simulations.
+    ///
+    /// Each index is permuted with [`RC5::permute_in_range`], so the same key always yields the
+    /// same order and no memory proportional to `len` is needed.
+    ///
+    /// Panics under the same conditions as [`RC5::permute_in_range`], which are checked before any
+    /// index is produced. In particular, `len` must be at least 2^-16 of the block domain, so for
+    /// 64-bit blocks such as RC5-32 this permutes at least 2^48 indices.
+    ///
+    /// Example usage:
+    /// ```
+    /// use rc5::RC5;
+    ///
+    /// let key = [
+    ///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+    ///     0x0F,
+    /// ];
+    ///
+    /// // RC5-8/12/16
+    /// let rc5 = RC5::<8, 12, 16, 1, 2, 26, 16>::new(key);
+    ///
+    /// let mut indices = [0; 10];
+    /// for (index, shuffled) in indices.iter_mut().zip(rc5.shuffle_indices(10)) {
+    ///     *index = shuffled;
+    /// }
+    /// assert_eq!(indices, [1, 5, 3, 7, 0, 4, 6, 2, 9, 8]);
+    /// ```
+    pub fn shuffle_indices(&self, len: usize) -> impl Iterator<Item = usize> + '_ {
+        if len > 0 {
+            check_cycle_walk_range::<BLOCK_SIZE>(len as u64);
+        }
+
+        let range = 0..len as u64;
+        range
+            .clone()
+            .map(move |index| self.permute_in_range(index, range.clone()) as usize)
+    }
+}
+
+impl<
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > RC5<32, ROUNDS, KEY_SIZE, 4, 8, EXPANDED_KEY_TABLE_LEN, KEY_AS_WORDS_LEN>
+{
+    /// Pseudorandomly permutes a 64-bit value by encrypting it as a single block.
+    ///
+    /// The value is laid out in little-endian byte order, matching the word order of the block.
+    ///
+    /// Example usage:
+    /// ```
+    /// use rc5::RC5;
+    ///
+    /// // RC5-32/12/16
+    /// let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]);
+    ///
+    /// let permuted = rc5.permute_u64(42);
+    /// assert_eq!(rc5.inverse_permute_u64(permuted), 42);
+    /// ```
+    pub fn permute_u64(&self, value: u64) -> u64 {
+        u64::from_le_bytes(self.encrypt(value.to_le_bytes()))
+    }
+
+    /// Reverts [`RC5::permute_u64`] by decrypting the value as a single block.
+    pub fn inverse_permute_u64(&self, value: u64) -> u64 {
+        u64::from_le_bytes(self.decrypt(value.to_le_bytes()))
+    }
+}
+
+/// Base 2 logarithm of the largest ratio between the block domain and the range length for which
+/// cycle walking is allowed, bounding the expected number of encryptions per value.
+const MAX_CYCLE_WALK_BITS: usize = 16;
+
+fn cycle_walk<const BLOCK_SIZE: usize>(
+    value: u64,
+    range: Range<u64>,
+    permute: impl Fn([u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE],
+) -> u64 {
+    assert!(range.contains(&value), "Value is not within the range.");
+    let len = range.end - range.start;
+    check_cycle_walk_range::<BLOCK_SIZE>(len);
+
+    let mut offset = value - range.start;
+    loop {
+        let mut block = [0; BLOCK_SIZE];
+        block.copy_from_slice(&offset.to_le_bytes()[..BLOCK_SIZE]);
+
+        let mut bytes = [0; 8];
+        bytes[..BLOCK_SIZE].copy_from_slice(&permute(block));
+        offset = u64::from_le_bytes(bytes);
+
+        if offset < len {
+            return range.start + offset;
+        }
+    }
+}
+
+/// Panics if cycle walking over a range of the length is not supported by the block size.
+fn check_cycle_walk_range<const BLOCK_SIZE: usize>(len: u64) {
+    assert!(BLOCK_SIZE <= 8, "Block size must not exceed 8 bytes.");
+    assert!(
+        BLOCK_SIZE == 8 || len <= 1 << (8 * BLOCK_SIZE),
+        "Range does not fit in a block."
+    );
+    assert!(
+        len >= 1 << (8 * BLOCK_SIZE).saturating_sub(MAX_CYCLE_WALK_BITS),
+        "Range is too small for the block size, cycle walking would take too long."
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RC5Fast;
+
+    #[test]
+    fn rc_32_12_16_permute_u64() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(key);
+
+        assert_eq!(rc5.permute_u64(0x7766554433221100), 0x9E8B08CF9B14DC2D);
+        assert_eq!(
+            rc5.inverse_permute_u64(0x9E8B08CF9B14DC2D),
+            0x7766554433221100
+        );
+    }
+
+    // Expected values are generated by `python3 scripts/reference_vectors.py permute`.
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_8_12_4_permute_in_range() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+
+        for (value, expected) in [(0, 254), (1, 948), (2, 383), (999, 490)] {
+            assert_eq!(rc5.permute_in_range(value, 0..1000), expected);
+            assert_eq!(rc5.inverse_permute_in_range(expected, 0..1000), value);
+        }
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_8_12_4_permute_in_range_with_offset() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        let expected = [3, 0, 5, 1, 4, 7, 8, 6, 9, 2];
+
+        for (value, expected) in (100..110).zip(expected) {
+            let permuted = rc5.permute_in_range(value, 100..110);
+            assert_eq!(permuted, 100 + expected);
+            assert_eq!(rc5.inverse_permute_in_range(permuted, 100..110), value);
+        }
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_8_12_4_shuffle_indices_is_permutation() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+
+        let mut seen = [false; 1000];
+        for index in rc5.shuffle_indices(seen.len()) {
+            assert!(!seen[index]);
+            seen[index] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn shuffle_indices_empty() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        assert_eq!(rc5.shuffle_indices(0).count(), 0);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    #[should_panic]
+    fn permute_in_range_value_out_of_range() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        rc5.permute_in_range(10, 0..10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn shuffle_indices_too_few_indices() {
+        let rc5 = RC5Fast::new([0x00; 16]);
+        let _ = rc5.shuffle_indices(10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn permute_in_range_range_too_small() {
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]);
+        rc5.permute_in_range(0, 0..1_000_000);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    #[should_panic]
+    fn permute_in_range_range_too_large() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        rc5.permute_in_range(0, 0..0x10001);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::RC5;
+
+/// RC5-32/12/16, the parameters originally suggested by Rivest.
+///
+/// Fast, but with little security margin: the differential attack of Biryukov and Kushilevitz
+/// breaks 12 rounds with 2^44 chosen plaintexts. Only use it where chosen-plaintext attacks are not
+/// a concern, e.g. for interoperability with existing systems.
+pub type RC5Fast = RC5<32, 12, 16, 4, 8, 26, 4>;
+
+/// RC5-32/20/16, the parameters used by the test vectors draft.
+///
+/// The best known differential attacks need more chosen plaintexts than the 64-bit block can
+/// provide beyond 16 rounds, leaving a margin of 4 rounds. A reasonable default when a 64-bit block
+/// is acceptable.
+pub type RC5Standard = RC5<32, 20, 16, 4, 8, 42, 4>;
+
+/// RC5-64/24/32 with a 256-bit key.
+///
+/// The 128-bit block pushes the collision bound far beyond any practical amount of data under a
+/// single key, and the 256-bit key rules out exhaustive search. Prefer it for long-lived keys and
+/// large volumes of data at the cost of speed.
+pub type RC5Conservative = RC5<64, 24, 32, 8, 16, 50, 4>;
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Number of key bits, i.e. the base 2 logarithm of the cost of an exhaustive key search.
+    pub const KEY_BITS: usize = 8 * KEY_SIZE;
+
+    /// Base 2 logarithm of the number of blocks after which collisions between ciphertext blocks
+    /// become likely (the birthday bound). Rekey well before encrypting this many blocks under a
+    /// single key.
+    pub const COLLISION_BOUND_LOG2: usize = 4 * BLOCK_SIZE;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_match_explicit_parameters() {
+        assert!(RC5Fast::new([0x00; 16]) == RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]));
+        assert!(RC5Standard::new([0x00; 16]) == RC5::<32, 20, 16, 4, 8, 42, 4>::new([0x00; 16]));
+        assert!(
+            RC5Conservative::new([0x00; 32]) == RC5::<64, 24, 32, 8, 16, 50, 4>::new([0x00; 32])
+        );
+    }
+
+    #[test]
+    fn preset_security_margins() {
+        assert_eq!(RC5Fast::KEY_BITS, 128);
+        assert_eq!(RC5Fast::COLLISION_BOUND_LOG2, 32);
+        assert_eq!(RC5Standard::KEY_BITS, 128);
+        assert_eq!(RC5Standard::COLLISION_BOUND_LOG2, 32);
+        assert_eq!(RC5Conservative::KEY_BITS, 256);
+        assert_eq!(RC5Conservative::COLLISION_BOUND_LOG2, 64);
+    }
+
+    // Expected values are generated by `python3 scripts/reference_vectors.py presets`.
+
+    #[test]
+    fn rc5_conservative_encrypt_decrypt() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B,
+            0x1C, 0x1D, 0x1E, 0x1F,
+        ];
+        let plaintext = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let ciphertext = [
+            0x8D, 0xE0, 0xCF, 0xAD, 0x45, 0xFD, 0xD3, 0x0D, 0x47, 0x69, 0xAC, 0xDF, 0xC5, 0xAB,
+            0xF7, 0xFC,
+        ];
+
+        let rc5 = RC5Conservative::new(key);
+        assert_eq!(rc5.encrypt(plaintext), ciphertext);
+        assert_eq!(rc5.decrypt(ciphertext), plaintext);
+    }
+}
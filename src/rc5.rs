@@ -1,10 +1,31 @@
-use core::cmp::max;
-
+#[cfg(feature = "hazmat")]
+use crate::consts::MagicConstant;
+#[cfg(feature = "trace")]
+use crate::key_schedule::MIXING_PASSES;
+#[cfg(any(feature = "hazmat", feature = "trace"))]
+use crate::key_schedule::{expand_key_traced, MIXING_ROTATION};
 use crate::{
-    bytes::ByteIntegerExt,
-    consts::{p, q},
+    arx::{join_words, rotation_amount, split_block},
+    consts,
+    error::Error,
+    key_schedule::expand_key,
+    words::{ByteIntegerExt, Choice},
 };
 
+/// Magic bytes identifying a serialized expanded key table.
+const SERIALIZED_MAGIC: [u8; 4] = *b"RC5K";
+/// Version of the serialization format produced by [`RC5::to_bytes`].
+const SERIALIZED_VERSION: u8 = 1;
+/// Length of the serialization header: magic, version, word bit size, rounds and key size.
+const SERIALIZED_HEADER_LEN: usize = SERIALIZED_MAGIC.len() + 4;
+
+/// Minimum number of rounds allowed by the `strict` feature.
+#[cfg(feature = "strict")]
+pub(crate) const STRICT_MIN_ROUNDS: usize = 12;
+/// Minimum key size in bytes allowed by the `strict` feature.
+#[cfg(feature = "strict")]
+pub(crate) const STRICT_MIN_KEY_SIZE: usize = 16;
+
 /// Provides the RC5 encryption algorithm.
 ///
 /// See https://www.grc.com/r&d/rc5.pdf for more info.
@@ -13,12 +34,14 @@ use crate::{
 /// ```
 /// use rc5::RC5;
 ///
-/// let key = [0x00, 0x01, 0x02, 0x03];
-/// let plaintext = [0x00, 0x01];
-/// let ciphertext = [0x21, 0x2A];
+/// let key = [
+///     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+/// ];
+/// let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+/// let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
 ///
-/// // RC5-8/12/4
-/// let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new(key);
+/// // RC5-32/12/16
+/// let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(key);
 ///
 /// assert_eq!(rc5.encrypt(plaintext), ciphertext);
 /// assert_eq!(rc5.decrypt(ciphertext), plaintext);
@@ -32,7 +55,7 @@ pub struct RC5<
     // generics in a const context.
     //
     // This is how the const generics below can be computed from the const generics above:
-    // - WORD_SIZE = 8 * WORD_BIT_SIZE
+    // - WORD_SIZE = WORD_BIT_SIZE / 8
     // - BLOCK_SIZE = 2 * WORD_SIZE
     // - EXPANDED_KEY_TABLE_LEN = 2 * (ROUNDS + 1)
     // - KEY_AS_WORDS_LEN = max(KEY_SIZE.div_ceil(WORD_SIZE), 1)
@@ -41,7 +64,8 @@ pub struct RC5<
     const EXPANDED_KEY_TABLE_LEN: usize,
     const KEY_AS_WORDS_LEN: usize,
 > {
-    expanded_key_table: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN],
+    pub(crate) expanded_key_table: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN],
+    swap_halves: bool,
 }
 
 impl<
@@ -63,129 +87,427 @@ impl<
         KEY_AS_WORDS_LEN,
     >
 {
+    /// Length of the serialized expanded key table produced by [`RC5::to_bytes`].
+    pub const SERIALIZED_LEN: usize = SERIALIZED_HEADER_LEN + EXPANDED_KEY_TABLE_LEN * WORD_SIZE;
+
+    /// The magic constant P_w as a little-endian word. See [`RC5::p`].
+    pub const P_W: [u8; WORD_SIZE] = consts::p::<WORD_BIT_SIZE, WORD_SIZE>();
+
+    /// The magic constant Q_w as a little-endian word. See [`RC5::q`].
+    pub const Q_W: [u8; WORD_SIZE] = consts::q::<WORD_BIT_SIZE, WORD_SIZE>();
+
+    /// Fails to compile when a cipher with weak parameters is created with the `strict` feature.
+    #[cfg(feature = "strict")]
+    const STRICT_PARAMETERS: () = assert!(
+        ROUNDS >= STRICT_MIN_ROUNDS && KEY_SIZE >= STRICT_MIN_KEY_SIZE,
+        "The strict feature requires at least 12 rounds and a key of at least 16 bytes."
+    );
+
     pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        Self::from_expanded_key_table(expand_key::<
+            WORD_BIT_SIZE,
+            KEY_SIZE,
+            WORD_SIZE,
+            EXPANDED_KEY_TABLE_LEN,
+            KEY_AS_WORDS_LEN,
+        >(key))
+    }
+
+    /// Returns the magic constant P_w = Odd((e - 2) * 2^w) as a little-endian word, which
+    /// initializes the expanded key table.
+    pub fn p() -> [u8; WORD_SIZE] {
+        Self::P_W
+    }
+
+    /// Returns the magic constant Q_w = Odd((phi - 1) * 2^w) as a little-endian word, which is
+    /// added between consecutive words of the expanded key table during initialization.
+    pub fn q() -> [u8; WORD_SIZE] {
+        Self::Q_W
+    }
+
+    /// Creates the cipher with the words of the expanded key table rotated by `rotation` instead of
+    /// the fixed 3 bits while mixing in the key.
+    ///
+    /// This deviates from the RC5 specification and only exists to interoperate with modified
+    /// variants of the algorithm.
+    #[cfg(feature = "hazmat")]
+    pub fn new_with_key_schedule_rotation(key: [u8; KEY_SIZE], rotation: u32) -> Self {
+        Self::from_expanded_key_table(expand_key_traced::<
+            WORD_BIT_SIZE,
+            KEY_SIZE,
+            WORD_SIZE,
+            EXPANDED_KEY_TABLE_LEN,
+            KEY_AS_WORDS_LEN,
+        >(
+            key, Self::p(), Self::q(), rotation as u128, |_, _| {}
+        ))
+    }
+
+    /// Creates the cipher with the magic constants P and Q derived from the irrational constants
+    /// `p` and `q` instead of e and the golden ratio.
+    ///
+    /// This deviates from the RC5 specification and only exists to interoperate with modified
+    /// variants of the algorithm.
+    #[cfg(feature = "hazmat")]
+    pub fn new_with_magic_constants(
+        key: [u8; KEY_SIZE],
+        p: MagicConstant,
+        q: MagicConstant,
+    ) -> Self {
+        Self::from_expanded_key_table(expand_key_traced::<
+            WORD_BIT_SIZE,
+            KEY_SIZE,
+            WORD_SIZE,
+            EXPANDED_KEY_TABLE_LEN,
+            KEY_AS_WORDS_LEN,
+        >(
+            key,
+            p.derive::<WORD_BIT_SIZE, WORD_SIZE>(),
+            q.derive::<WORD_BIT_SIZE, WORD_SIZE>(),
+            MIXING_ROTATION,
+            |_, _| {},
+        ))
+    }
+
+    pub(crate) fn from_expanded_key_table(
+        expanded_key_table: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN],
+    ) -> Self {
+        #[cfg(feature = "strict")]
+        let () = Self::STRICT_PARAMETERS;
+
         Self {
-            expanded_key_table: Self::expand_key(key),
+            expanded_key_table,
+            swap_halves: false,
         }
     }
 
-    fn expand_key(key: [u8; KEY_SIZE]) -> [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN] {
-        let p = p::<WORD_BIT_SIZE, WORD_SIZE>();
-        let q = q::<WORD_BIT_SIZE, WORD_SIZE>();
+    /// Creates a cipher with the same options as this one but a different expanded key table.
+    pub(crate) fn with_expanded_key_table(
+        &self,
+        expanded_key_table: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN],
+    ) -> Self {
+        Self {
+            expanded_key_table,
+            swap_halves: self.swap_halves,
+        }
+    }
 
-        // Convert key from byte array to a word array.
-        let mut key_as_words: [[u8; WORD_SIZE]; KEY_AS_WORDS_LEN] =
-            [[0; WORD_SIZE]; KEY_AS_WORDS_LEN];
+    /// Swaps the order of the A and B halves when loading and storing blocks.
+    ///
+    /// Some implementations store the B half of a block before the A half. Enabling this makes the
+    /// cipher compatible with them without having to transpose the halves of every block manually.
+    pub fn with_swapped_halves(mut self) -> Self {
+        self.swap_halves = true;
+        self
+    }
 
-        for idx in (0..KEY_SIZE).rev() {
-            let key_word = &mut key_as_words[idx / WORD_SIZE];
-            *key_word = key_word
-                .rotate_left(8)
-                .wrapping_add(<[u8; WORD_SIZE]>::from_slice(&[key[idx]]));
+    /// Serializes the expanded key table into `output`, returning the number of bytes written.
+    ///
+    /// The encoding consists of the magic bytes `RC5K`, a format version byte, the word bit size,
+    /// the number of rounds and the key size (one byte each), followed by the words of the expanded
+    /// key table in little-endian order. Options such as swapped halves are not part of the encoding.
+    pub fn to_bytes(&self, output: &mut [u8]) -> Result<usize, Error> {
+        if output.len() < Self::SERIALIZED_LEN {
+            return Err(Error::BufferTooSmall);
         }
 
-        // Create expanded key table.
-        let mut expanded_key_table: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN] =
-            [[0; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN];
+        let (header, words) = output[..Self::SERIALIZED_LEN].split_at_mut(SERIALIZED_HEADER_LEN);
+        header[..SERIALIZED_MAGIC.len()].copy_from_slice(&SERIALIZED_MAGIC);
+        header[SERIALIZED_MAGIC.len()..].copy_from_slice(&Self::serialized_parameters()?);
+
+        for (chunk, word) in words
+            .chunks_exact_mut(WORD_SIZE)
+            .zip(self.expanded_key_table.iter())
+        {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
 
-        expanded_key_table[0] = p;
+        Ok(Self::SERIALIZED_LEN)
+    }
 
-        for idx in 1..expanded_key_table.len() {
-            expanded_key_table[idx] = expanded_key_table[idx - 1].wrapping_add(q);
+    /// Deserializes an expanded key table previously produced by [`RC5::to_bytes`].
+    ///
+    /// Fails if the input is malformed or was produced for a different parameter set.
+    pub fn from_bytes(input: &[u8]) -> Result<Self, Error> {
+        if input.len() != Self::SERIALIZED_LEN {
+            return Err(Error::InvalidLength);
         }
 
-        // Mix the word array and expanded key table.
-        let mut expanded_key_word_idx = 0;
-        let mut key_word_idx = 0;
-        let mut last_expanded_key_word = [0; WORD_SIZE];
-        let mut last_key_word = [0; WORD_SIZE];
-
-        for _ in 0..3 * max(KEY_AS_WORDS_LEN, EXPANDED_KEY_TABLE_LEN) {
-            let expanded_key_word = &mut expanded_key_table[expanded_key_word_idx];
-            *expanded_key_word = expanded_key_word
-                .wrapping_add(last_expanded_key_word)
-                .wrapping_add(last_key_word)
-                .rotate_left(3);
-            last_expanded_key_word = *expanded_key_word;
-
-            let key_word = &mut key_as_words[key_word_idx];
-            *key_word = key_word
-                .wrapping_add(last_expanded_key_word)
-                .wrapping_add(last_key_word)
-                .rotate_left(u128::from_le_bytes(<[u8; 16]>::from_slice(
-                    &expanded_key_word.wrapping_add(last_key_word),
-                )));
-            last_key_word = *key_word;
-
-            expanded_key_word_idx = (expanded_key_word_idx + 1) % expanded_key_table.len();
-            key_word_idx = (key_word_idx + 1) % key_as_words.len();
+        let (header, words) = input.split_at(SERIALIZED_HEADER_LEN);
+        let (magic, parameters) = header.split_at(SERIALIZED_MAGIC.len());
+        if magic != SERIALIZED_MAGIC {
+            return Err(Error::InvalidMagic);
+        }
+        if parameters[0] != SERIALIZED_VERSION {
+            return Err(Error::UnsupportedVersion);
+        }
+        if parameters != Self::serialized_parameters()? {
+            return Err(Error::ParameterMismatch);
         }
 
-        expanded_key_table
+        let mut expanded_key_table = [[0; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN];
+        for (word, chunk) in expanded_key_table
+            .iter_mut()
+            .zip(words.chunks_exact(WORD_SIZE))
+        {
+            *word = <[u8; WORD_SIZE]>::from_le_slice(chunk);
+        }
+
+        Ok(Self::from_expanded_key_table(expanded_key_table))
+    }
+
+    fn serialized_parameters() -> Result<[u8; 4], Error> {
+        let to_u8 = |value: usize| u8::try_from(value).map_err(|_| Error::ParameterMismatch);
+        Ok([
+            SERIALIZED_VERSION,
+            to_u8(WORD_BIT_SIZE)?,
+            to_u8(ROUNDS)?,
+            to_u8(KEY_SIZE)?,
+        ])
     }
 
     /// Encrypts the plaintext block returning ciphertext block.
     pub fn encrypt(&self, plaintext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
-        let (a, b) = plaintext.split_at(WORD_SIZE);
-        let mut a: [u8; WORD_SIZE] = a.try_into().unwrap();
-        let mut b: [u8; WORD_SIZE] = b.try_into().unwrap();
+        self.encrypt_traced(plaintext, |_, _, _| {})
+    }
+
+    /// Decrypts the ciphertext block returning plaintext block.
+    pub fn decrypt(&self, ciphertext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        self.decrypt_traced(ciphertext, |_, _, _| {})
+    }
+
+    /// Encrypts the blocks of `src` into `dst` block by block (ECB), returning the number of bytes
+    /// written.
+    ///
+    /// The buffers are separate so that, for example, DMA double buffering does not require copying
+    /// into a scratch buffer first. Fails if `src` is not a whole number of blocks or if `dst` is
+    /// smaller than `src`.
+    pub fn encrypt_to(&self, src: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+        process_blocks(src, dst, |block| self.encrypt(block))
+    }
+
+    /// Decrypts the blocks of `src` into `dst` block by block (ECB), returning the number of bytes
+    /// written.
+    ///
+    /// Fails if `src` is not a whole number of blocks or if `dst` is smaller than `src`.
+    pub fn decrypt_to(&self, src: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+        process_blocks(src, dst, |block| self.decrypt(block))
+    }
+
+    /// Encrypts the plaintext block returning ciphertext block.
+    ///
+    /// The trace callback receives the round index along with the A and B halves after the initial
+    /// key addition (round 0) and after every subsequent round.
+    #[cfg(feature = "trace")]
+    pub fn encrypt_with_trace(
+        &self,
+        plaintext: [u8; BLOCK_SIZE],
+        trace: impl FnMut(usize, &[u8; WORD_SIZE], &[u8; WORD_SIZE]),
+    ) -> [u8; BLOCK_SIZE] {
+        self.encrypt_traced(plaintext, trace)
+    }
+
+    /// Decrypts the ciphertext block returning plaintext block.
+    ///
+    /// The trace callback receives the round index along with the A and B halves after every round
+    /// (in reverse order) and after the final key subtraction (round 0).
+    #[cfg(feature = "trace")]
+    pub fn decrypt_with_trace(
+        &self,
+        ciphertext: [u8; BLOCK_SIZE],
+        trace: impl FnMut(usize, &[u8; WORD_SIZE], &[u8; WORD_SIZE]),
+    ) -> [u8; BLOCK_SIZE] {
+        self.decrypt_traced(ciphertext, trace)
+    }
+
+    /// Returns snapshots of the expanded key table (the S-table) during key expansion.
+    ///
+    /// The first snapshot holds the table initialized from the magic constants P and Q, the
+    /// following ones the table after each of the three passes mixing in the key. The last snapshot
+    /// is the final expanded key table.
+    #[cfg(feature = "trace")]
+    pub fn key_expansion_snapshots(
+        key: [u8; KEY_SIZE],
+    ) -> [[[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN]; MIXING_PASSES + 1] {
+        let mut snapshots = [[[0; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN]; MIXING_PASSES + 1];
+        expand_key_traced::<
+            WORD_BIT_SIZE,
+            KEY_SIZE,
+            WORD_SIZE,
+            EXPANDED_KEY_TABLE_LEN,
+            KEY_AS_WORDS_LEN,
+        >(key, Self::p(), Self::q(), MIXING_ROTATION, |pass, table| {
+            snapshots[pass] = *table
+        });
+        snapshots
+    }
+
+    /// Returns the expanded key table (the mixed S-table).
+    #[cfg(feature = "trace")]
+    pub fn expanded_key_table(&self) -> &[[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN] {
+        &self.expanded_key_table
+    }
+
+    pub(crate) fn encrypt_traced(
+        &self,
+        plaintext: [u8; BLOCK_SIZE],
+        mut trace: impl FnMut(usize, &[u8; WORD_SIZE], &[u8; WORD_SIZE]),
+    ) -> [u8; BLOCK_SIZE] {
+        let [mut a, mut b] = self.load_block(plaintext);
 
         a = a.wrapping_add(self.expanded_key_table[0]);
         b = b.wrapping_add(self.expanded_key_table[1]);
+        trace(0, &a, &b);
 
         for idx in 1..=ROUNDS {
             a = a
                 .bitxor(b)
-                .rotate_left(u128::from_le_bytes(<[u8; 16]>::from_slice(&b)))
+                .rotate_left(rotation_amount(b))
                 .wrapping_add(self.expanded_key_table[2 * idx]);
             b = b
                 .bitxor(a)
-                .rotate_left(u128::from_le_bytes(<[u8; 16]>::from_slice(&a)))
+                .rotate_left(rotation_amount(a))
                 .wrapping_add(self.expanded_key_table[2 * idx + 1]);
+            trace(idx, &a, &b);
         }
 
-        let mut output = [0; BLOCK_SIZE];
-
-        let (left, right) = output.split_at_mut(WORD_SIZE);
-        left.copy_from_slice(&a);
-        right.copy_from_slice(&b);
-
-        output
+        self.store_block([a, b])
     }
 
-    /// Decrypts the ciphertext block returning plaintext block.
-    pub fn decrypt(&self, ciphertext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
-        let (a, b) = ciphertext.split_at(WORD_SIZE);
-        let mut a: [u8; WORD_SIZE] = a.try_into().unwrap();
-        let mut b: [u8; WORD_SIZE] = b.try_into().unwrap();
+    fn decrypt_traced(
+        &self,
+        ciphertext: [u8; BLOCK_SIZE],
+        mut trace: impl FnMut(usize, &[u8; WORD_SIZE], &[u8; WORD_SIZE]),
+    ) -> [u8; BLOCK_SIZE] {
+        let [mut a, mut b] = self.load_block(ciphertext);
 
         for idx in (1..=ROUNDS).rev() {
             b = b
                 .wrapping_sub(self.expanded_key_table[2 * idx + 1])
-                .rotate_right(u128::from_le_bytes(<[u8; 16]>::from_slice(&a)))
+                .rotate_right(rotation_amount(a))
                 .bitxor(a);
             a = a
                 .wrapping_sub(self.expanded_key_table[2 * idx])
-                .rotate_right(u128::from_le_bytes(<[u8; 16]>::from_slice(&b)))
+                .rotate_right(rotation_amount(b))
                 .bitxor(b);
+            trace(idx, &a, &b);
         }
 
         b = b.wrapping_sub(self.expanded_key_table[1]);
         a = a.wrapping_sub(self.expanded_key_table[0]);
+        trace(0, &a, &b);
 
-        let mut output = [0; BLOCK_SIZE];
+        self.store_block([a, b])
+    }
 
-        let (left, right) = output.split_at_mut(WORD_SIZE);
-        left.copy_from_slice(&a);
-        right.copy_from_slice(&b);
+    pub(crate) fn load_block(&self, block: [u8; BLOCK_SIZE]) -> [[u8; WORD_SIZE]; 2] {
+        let [a, b] = split_block(block);
+        if self.swap_halves {
+            [b, a]
+        } else {
+            [a, b]
+        }
+    }
+
+    pub(crate) fn store_block(&self, [a, b]: [[u8; WORD_SIZE]; 2]) -> [u8; BLOCK_SIZE] {
+        if self.swap_halves {
+            join_words([b, a])
+        } else {
+            join_words([a, b])
+        }
+    }
+}
 
-        output
+/// Compares the expanded key tables in constant time, so that comparing ciphers, e.g. when
+/// deduplicating or caching them, does not leak how many key words match through timing.
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > PartialEq
+    for RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    fn eq(&self, other: &Self) -> bool {
+        let equal = self
+            .expanded_key_table
+            .iter()
+            .zip(&other.expanded_key_table)
+            .fold(Choice::from(1), |equal, (a, b)| equal & a.ct_eq(b));
+        self.swap_halves == other.swap_halves && bool::from(equal)
     }
 }
 
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > Eq
+    for RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+}
+
+fn process_blocks<const BLOCK_SIZE: usize>(
+    src: &[u8],
+    dst: &mut [u8],
+    process: impl Fn([u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE],
+) -> Result<usize, Error> {
+    let src_blocks = src.chunks_exact(BLOCK_SIZE);
+    if !src_blocks.remainder().is_empty() {
+        return Err(Error::InvalidLength);
+    }
+    if dst.len() < src.len() {
+        return Err(Error::BufferTooSmall);
+    }
+
+    for (src_block, dst_block) in src_blocks.zip(dst.chunks_exact_mut(BLOCK_SIZE)) {
+        let mut block = [0; BLOCK_SIZE];
+        block.copy_from_slice(src_block);
+        dst_block.copy_from_slice(&process(block));
+    }
+
+    Ok(src.len())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arx::test_utils;
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn eq() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        let rc5_b = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        let rc5_c = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x04]);
+
+        assert!(rc5 == rc5_b);
+        assert!(rc5 != rc5_c);
+        assert!(rc5 != rc5_b.with_swapped_halves());
+    }
 
     #[test]
     fn rc_32_12_16_encrypt_decrypt_a() {
@@ -266,6 +588,7 @@ mod tests {
     // The following test cases are taken from
     // https://datatracker.ietf.org/doc/html/draft-krovetz-rc6-rc5-vectors-00#section-4.
 
+    #[cfg(not(feature = "strict"))]
     #[test]
     fn rc_8_12_4_encrypt_decrypt() {
         let key = [0x00, 0x01, 0x02, 0x03];
@@ -274,6 +597,7 @@ mod tests {
         assert_encrypt_decrypt_roundtrip::<8, 12, 4, 1, 2, 26, 4>(key, plaintext, ciphertext);
     }
 
+    #[cfg(not(feature = "strict"))]
     #[test]
     fn rc_16_16_8_encrypt_decrypt() {
         let key = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
@@ -330,6 +654,7 @@ mod tests {
         assert_encrypt_decrypt_roundtrip::<128, 28, 32, 16, 32, 58, 2>(key, plaintext, ciphertext);
     }
 
+    #[cfg(not(feature = "strict"))]
     #[test]
     fn rc_24_4_0_encrypt_decrypt() {
         let key = [];
@@ -338,6 +663,7 @@ mod tests {
         assert_encrypt_decrypt_roundtrip::<24, 4, 0, 3, 6, 10, 1>(key, plaintext, ciphertext);
     }
 
+    #[cfg(not(feature = "strict"))]
     #[test]
     fn rc_80_4_12_encrypt_decrypt() {
         let key = [
@@ -354,6 +680,302 @@ mod tests {
         assert_encrypt_decrypt_roundtrip::<80, 4, 12, 10, 20, 10, 2>(key, plaintext, ciphertext);
     }
 
+    #[test]
+    fn rc_32_12_16_encrypt_decrypt_swapped_halves() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x44, 0x55, 0x66, 0x77, 0x00, 0x11, 0x22, 0x33];
+        let ciphertext = [0xCF, 0x08, 0x8B, 0x9E, 0x2D, 0xDC, 0x14, 0x9B];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(key).with_swapped_halves();
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            plaintext,
+            ciphertext,
+        );
+    }
+
+    #[cfg(all(feature = "trace", not(feature = "strict")))]
+    #[test]
+    fn rc_8_12_4_encrypt_decrypt_trace() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+
+        let mut encrypt_states = [(0, [0x00], [0x00]); 13];
+        let mut encrypt_count = 0;
+        let ciphertext = rc5.encrypt_with_trace([0x00, 0x01], |round, a, b| {
+            encrypt_states[encrypt_count] = (round, *a, *b);
+            encrypt_count += 1;
+        });
+        assert_eq!(ciphertext, [0x21, 0x2A]);
+        assert_eq!(encrypt_count, 13);
+        assert_eq!(encrypt_states[12], (12, [0x21], [0x2A]));
+
+        let table = rc5.expanded_key_table();
+        assert_eq!(
+            encrypt_states[0],
+            (0, [table[0][0]], [0x01u8.wrapping_add(table[1][0])])
+        );
+
+        let mut decrypt_count = 0;
+        let plaintext = rc5.decrypt_with_trace(ciphertext, |round, a, b| {
+            // Decryption walks back through the same intermediate states.
+            if round > 0 {
+                let (_, expected_a, expected_b) = encrypt_states[round - 1];
+                assert_eq!((*a, *b), (expected_a, expected_b));
+            }
+            decrypt_count += 1;
+        });
+        assert_eq!(plaintext, [0x00, 0x01]);
+        assert_eq!(decrypt_count, 13);
+    }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    fn rc_32_12_16_key_expansion_snapshots() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let snapshots = RC5::<32, 12, 16, 4, 8, 26, 4>::key_expansion_snapshots(key);
+
+        assert_eq!(snapshots[0][0], [0x63, 0x51, 0xE1, 0xB7]);
+        assert_eq!(
+            &snapshots[3],
+            RC5::<32, 12, 16, 4, 8, 26, 4>::new(key).expanded_key_table()
+        );
+        // Every mixing pass modifies every word of the table.
+        for pass in 1..snapshots.len() {
+            for (word, previous_word) in snapshots[pass].iter().zip(snapshots[pass - 1].iter()) {
+                assert_ne!(word, previous_word);
+            }
+        }
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn rc_32_12_16_encrypt_decrypt_key_schedule_rotation_3() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new_with_key_schedule_rotation(key, 3);
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            plaintext,
+            ciphertext,
+        );
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn rc_32_12_16_encrypt_decrypt_key_schedule_rotation_5() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x53, 0xD9, 0x5A, 0x4C, 0xDB, 0xA0, 0x2D, 0x4C];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new_with_key_schedule_rotation(key, 5);
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            plaintext,
+            ciphertext,
+        );
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn rc_32_12_16_encrypt_decrypt_magic_constants_e_golden_ratio() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new_with_magic_constants(
+            key,
+            MagicConstant::E,
+            MagicConstant::GoldenRatio,
+        );
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            plaintext,
+            ciphertext,
+        );
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn rc_32_12_16_encrypt_decrypt_magic_constants_pi_sqrt2() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0xDF, 0x5B, 0x69, 0x33, 0x5C, 0x93, 0xFA, 0x82];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new_with_magic_constants(
+            key,
+            MagicConstant::Pi,
+            MagicConstant::Sqrt2,
+        );
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            plaintext,
+            ciphertext,
+        );
+    }
+
+    #[test]
+    fn rc_32_magic_constants() {
+        // P32 = B7E15163 and Q32 = 9E3779B9 as given in the RC5 paper.
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::p(),
+            0xB7E1_5163u32.to_le_bytes()
+        );
+        assert_eq!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::q(),
+            0x9E37_79B9u32.to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn rc_16_magic_constants_const() {
+        // P16 = B7E1 and Q16 = 9E37 as given in the RC5 paper, evaluated at compile time.
+        const P_16: [u8; 2] = RC5::<16, 16, 8, 2, 4, 34, 4>::P_W;
+        const Q_16: [u8; 2] = RC5::<16, 16, 8, 2, 4, 34, 4>::Q_W;
+
+        assert_eq!(P_16, [0xE1, 0xB7]);
+        assert_eq!(Q_16, [0x37, 0x9E]);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_8_12_4_encrypt_to_decrypt_to() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        let plaintext = [0x00, 0x01, 0x00, 0x01, 0xAB, 0xCD];
+        let mut ciphertext = [0; 8];
+        let mut decrypted = [0; 6];
+
+        assert_eq!(rc5.encrypt_to(&plaintext, &mut ciphertext), Ok(6));
+        assert_eq!(ciphertext[..4], [0x21, 0x2A, 0x21, 0x2A]);
+        assert_eq!(ciphertext[4..6], rc5.encrypt([0xAB, 0xCD]));
+        assert_eq!(ciphertext[6..], [0x00, 0x00]);
+
+        assert_eq!(rc5.decrypt_to(&ciphertext[..6], &mut decrypted), Ok(6));
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn encrypt_to_invalid_length() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        assert_eq!(
+            rc5.encrypt_to(&[0x00; 3], &mut [0x00; 4]),
+            Err(Error::InvalidLength)
+        );
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn decrypt_to_buffer_too_small() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        assert_eq!(
+            rc5.decrypt_to(&[0x00; 4], &mut [0x00; 2]),
+            Err(Error::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn rc_32_12_16_serialize_roundtrip() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E];
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(key);
+
+        let mut bytes = [0; RC5::<32, 12, 16, 4, 8, 26, 4>::SERIALIZED_LEN];
+        assert_eq!(rc5.to_bytes(&mut bytes), Ok(bytes.len()));
+        assert_eq!(bytes[..8], [b'R', b'C', b'5', b'K', 1, 32, 12, 16]);
+
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::from_bytes(&bytes).unwrap();
+        assert_eq!(rc5.encrypt(plaintext), ciphertext);
+        assert_eq!(rc5.decrypt(ciphertext), plaintext);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn serialize_buffer_too_small() {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        let mut bytes = [0; RC5::<8, 12, 4, 1, 2, 26, 4>::SERIALIZED_LEN - 1];
+        assert_eq!(rc5.to_bytes(&mut bytes), Err(Error::BufferTooSmall));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn deserialize_invalid_length() {
+        let bytes = serialized_rc5_8_12_4();
+        assert!(matches!(
+            RC5::<8, 12, 4, 1, 2, 26, 4>::from_bytes(&bytes[1..]),
+            Err(Error::InvalidLength)
+        ));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn deserialize_invalid_magic() {
+        let mut bytes = serialized_rc5_8_12_4();
+        bytes[0] = b'X';
+        assert!(matches!(
+            RC5::<8, 12, 4, 1, 2, 26, 4>::from_bytes(&bytes),
+            Err(Error::InvalidMagic)
+        ));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn deserialize_unsupported_version() {
+        let mut bytes = serialized_rc5_8_12_4();
+        bytes[4] = 2;
+        assert!(matches!(
+            RC5::<8, 12, 4, 1, 2, 26, 4>::from_bytes(&bytes),
+            Err(Error::UnsupportedVersion)
+        ));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn deserialize_parameter_mismatch() {
+        let bytes = serialized_rc5_8_12_4();
+        // Same expanded key table length and word size, different key size.
+        assert!(matches!(
+            RC5::<8, 12, 5, 1, 2, 26, 5>::from_bytes(&bytes),
+            Err(Error::ParameterMismatch)
+        ));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    fn serialized_rc5_8_12_4() -> [u8; RC5::<8, 12, 4, 1, 2, 26, 4>::SERIALIZED_LEN] {
+        let rc5 = RC5::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        let mut bytes = [0; RC5::<8, 12, 4, 1, 2, 26, 4>::SERIALIZED_LEN];
+        rc5.to_bytes(&mut bytes).unwrap();
+        bytes
+    }
+
     fn assert_encrypt_decrypt_roundtrip<
         const WORD_BIT_SIZE: usize,
         const ROUNDS: usize,
@@ -377,10 +999,11 @@ mod tests {
             KEY_AS_WORDS_LEN,
         >::new(key);
 
-        let output_ciphertext = rc5.encrypt(plaintext);
-        assert_eq!(output_ciphertext, ciphertext);
-
-        let output_plaintext = rc5.decrypt(output_ciphertext);
-        assert_eq!(output_plaintext, plaintext);
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            plaintext,
+            ciphertext,
+        );
     }
 }
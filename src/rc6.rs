@@ -0,0 +1,343 @@
+#[cfg(feature = "strict")]
+use crate::rc5::{STRICT_MIN_KEY_SIZE, STRICT_MIN_ROUNDS};
+use crate::{
+    arx::{join_words, rotation_amount, split_block},
+    key_schedule::expand_key,
+    words::{ByteIntegerExt, Choice},
+};
+
+/// Provides the RC6 encryption algorithm.
+///
+/// RC6 shares the word size, rounds and key size parameterization as well as the key expansion of
+/// RC5, but operates on four words per block and adds a word multiplication to the round function.
+///
+/// See https://people.csail.mit.edu/rivest/pubs/RRSY98.pdf for more info.
+///
+/// Example usage:
+/// ```
+/// use rc5::RC6;
+///
+/// let key = [0x00; 16];
+/// let plaintext = [0x00; 16];
+/// let ciphertext = [
+///     0x8F, 0xC3, 0xA5, 0x36, 0x56, 0xB1, 0xF7, 0x78, 0xC1, 0x29, 0xDF, 0x4E, 0x98, 0x48, 0xA4,
+///     0x1E,
+/// ];
+///
+/// // RC6-32/20/16
+/// let rc6 = RC6::<32, 20, 16, 4, 16, 44, 4>::new(key);
+///
+/// assert_eq!(rc6.encrypt(plaintext), ciphertext);
+/// assert_eq!(rc6.decrypt(ciphertext), plaintext);
+/// ```
+pub struct RC6<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    // The following const generics can be computed from the above const generics:
+    // - WORD_SIZE = WORD_BIT_SIZE / 8
+    // - BLOCK_SIZE = 4 * WORD_SIZE
+    // - EXPANDED_KEY_TABLE_LEN = 2 * (ROUNDS + 2)
+    // - KEY_AS_WORDS_LEN = max(KEY_SIZE.div_ceil(WORD_SIZE), 1)
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    expanded_key_table: [[u8; WORD_SIZE]; EXPANDED_KEY_TABLE_LEN],
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC6<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Fails to compile when a cipher with weak parameters is created with the `strict` feature.
+    #[cfg(feature = "strict")]
+    const STRICT_PARAMETERS: () = assert!(
+        ROUNDS >= STRICT_MIN_ROUNDS && KEY_SIZE >= STRICT_MIN_KEY_SIZE,
+        "The strict feature requires at least 12 rounds and a key of at least 16 bytes."
+    );
+
+    pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        #[cfg(feature = "strict")]
+        let () = Self::STRICT_PARAMETERS;
+
+        Self {
+            expanded_key_table: expand_key::<
+                WORD_BIT_SIZE,
+                KEY_SIZE,
+                WORD_SIZE,
+                EXPANDED_KEY_TABLE_LEN,
+                KEY_AS_WORDS_LEN,
+            >(key),
+        }
+    }
+
+    /// Encrypts the plaintext block returning ciphertext block.
+    pub fn encrypt(&self, plaintext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let [mut a, mut b, mut c, mut d] = split_block(plaintext);
+
+        b = b.wrapping_add(self.expanded_key_table[0]);
+        d = d.wrapping_add(self.expanded_key_table[1]);
+
+        for idx in 1..=ROUNDS {
+            let t = Self::quadratic(b);
+            let u = Self::quadratic(d);
+            a = a
+                .bitxor(t)
+                .rotate_left(rotation_amount(u))
+                .wrapping_add(self.expanded_key_table[2 * idx]);
+            c = c
+                .bitxor(u)
+                .rotate_left(rotation_amount(t))
+                .wrapping_add(self.expanded_key_table[2 * idx + 1]);
+
+            (a, b, c, d) = (b, c, d, a);
+        }
+
+        a = a.wrapping_add(self.expanded_key_table[2 * ROUNDS + 2]);
+        c = c.wrapping_add(self.expanded_key_table[2 * ROUNDS + 3]);
+
+        join_words([a, b, c, d])
+    }
+
+    /// Decrypts the ciphertext block returning plaintext block.
+    pub fn decrypt(&self, ciphertext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let [mut a, mut b, mut c, mut d] = split_block(ciphertext);
+
+        c = c.wrapping_sub(self.expanded_key_table[2 * ROUNDS + 3]);
+        a = a.wrapping_sub(self.expanded_key_table[2 * ROUNDS + 2]);
+
+        for idx in (1..=ROUNDS).rev() {
+            (a, b, c, d) = (d, a, b, c);
+
+            let u = Self::quadratic(d);
+            let t = Self::quadratic(b);
+            c = c
+                .wrapping_sub(self.expanded_key_table[2 * idx + 1])
+                .rotate_right(rotation_amount(t))
+                .bitxor(u);
+            a = a
+                .wrapping_sub(self.expanded_key_table[2 * idx])
+                .rotate_right(rotation_amount(u))
+                .bitxor(t);
+        }
+
+        d = d.wrapping_sub(self.expanded_key_table[1]);
+        b = b.wrapping_sub(self.expanded_key_table[0]);
+
+        join_words([a, b, c, d])
+    }
+
+    /// Computes `(x * (2x + 1)) <<< lg w`.
+    fn quadratic(x: [u8; WORD_SIZE]) -> [u8; WORD_SIZE] {
+        let one = <[u8; WORD_SIZE]>::from_le_slice(&[0x01]);
+        x.wrapping_mul(x.wrapping_add(x).wrapping_add(one))
+            .rotate_left(WORD_BIT_SIZE.ilog2() as u128)
+    }
+}
+
+/// Compares the expanded key tables in constant time, so that comparing ciphers, e.g. when
+/// deduplicating or caching them, does not leak how many key words match through timing.
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > PartialEq
+    for RC6<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    fn eq(&self, other: &Self) -> bool {
+        let equal = self
+            .expanded_key_table
+            .iter()
+            .zip(&other.expanded_key_table)
+            .fold(Choice::from(1), |equal, (a, b)| equal & a.ct_eq(b));
+        bool::from(equal)
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > Eq
+    for RC6<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arx::test_utils;
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn eq() {
+        let rc6 = RC6::<8, 12, 4, 1, 4, 28, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        let rc6_b = RC6::<8, 12, 4, 1, 4, 28, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        let rc6_c = RC6::<8, 12, 4, 1, 4, 28, 4>::new([0x00, 0x01, 0x02, 0x04]);
+
+        assert!(rc6 == rc6_b);
+        assert!(rc6 != rc6_c);
+    }
+
+    // The following test cases are taken from the RC6 AES submission.
+
+    #[test]
+    fn rc_32_20_16_encrypt_decrypt_a() {
+        let key = [0x00; 16];
+        let plaintext = [0x00; 16];
+        let ciphertext = [
+            0x8F, 0xC3, 0xA5, 0x36, 0x56, 0xB1, 0xF7, 0x78, 0xC1, 0x29, 0xDF, 0x4E, 0x98, 0x48,
+            0xA4, 0x1E,
+        ];
+        assert_encrypt_decrypt_roundtrip::<32, 20, 16, 4, 16, 44, 4>(key, plaintext, ciphertext);
+    }
+
+    #[test]
+    fn rc_32_20_16_encrypt_decrypt_b() {
+        let key = [
+            0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01, 0x12, 0x23, 0x34, 0x45, 0x56,
+            0x67, 0x78,
+        ];
+        let plaintext = [
+            0x02, 0x13, 0x24, 0x35, 0x46, 0x57, 0x68, 0x79, 0x8A, 0x9B, 0xAC, 0xBD, 0xCE, 0xDF,
+            0xE0, 0xF1,
+        ];
+        let ciphertext = [
+            0x52, 0x4E, 0x19, 0x2F, 0x47, 0x15, 0xC6, 0x23, 0x1F, 0x51, 0xF6, 0x36, 0x7E, 0xA4,
+            0x3F, 0x18,
+        ];
+        assert_encrypt_decrypt_roundtrip::<32, 20, 16, 4, 16, 44, 4>(key, plaintext, ciphertext);
+    }
+
+    #[test]
+    fn rc_32_20_24_encrypt_decrypt() {
+        let key = [0x00; 24];
+        let plaintext = [0x00; 16];
+        let ciphertext = [
+            0x6C, 0xD6, 0x1B, 0xCB, 0x19, 0x0B, 0x30, 0x38, 0x4E, 0x8A, 0x3F, 0x16, 0x86, 0x90,
+            0xAE, 0x82,
+        ];
+        assert_encrypt_decrypt_roundtrip::<32, 20, 24, 4, 16, 44, 6>(key, plaintext, ciphertext);
+    }
+
+    #[test]
+    fn rc_32_20_32_encrypt_decrypt() {
+        let key = [0x00; 32];
+        let plaintext = [0x00; 16];
+        let ciphertext = [
+            0x8F, 0x5F, 0xBD, 0x05, 0x10, 0xD1, 0x5F, 0xA8, 0x93, 0xFA, 0x3F, 0xDA, 0x6E, 0x85,
+            0x7E, 0xC2,
+        ];
+        assert_encrypt_decrypt_roundtrip::<32, 20, 32, 4, 16, 44, 8>(key, plaintext, ciphertext);
+    }
+
+    // The following test cases are taken from
+    // https://datatracker.ietf.org/doc/html/draft-krovetz-rc6-rc5-vectors-00#section-3.
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_8_12_4_encrypt_decrypt() {
+        let key = [0x00, 0x01, 0x02, 0x03];
+        let plaintext = [0x00, 0x01, 0x02, 0x03];
+        let ciphertext = [0xAE, 0xFC, 0x46, 0x12];
+        assert_encrypt_decrypt_roundtrip::<8, 12, 4, 1, 4, 28, 4>(key, plaintext, ciphertext);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_16_16_8_encrypt_decrypt() {
+        let key = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+        let plaintext = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+        let ciphertext = [0x2F, 0xF0, 0xB6, 0x8E, 0xAE, 0xFF, 0xAD, 0x5B];
+        assert_encrypt_decrypt_roundtrip::<16, 16, 8, 2, 8, 36, 4>(key, plaintext, ciphertext);
+    }
+
+    #[test]
+    fn rc_32_20_16_encrypt_decrypt_c() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let ciphertext = [
+            0x3A, 0x96, 0xF9, 0xC7, 0xF6, 0x75, 0x5C, 0xFE, 0x46, 0xF0, 0x0E, 0x3D, 0xCD, 0x5D,
+            0x2A, 0x3C,
+        ];
+        assert_encrypt_decrypt_roundtrip::<32, 20, 16, 4, 16, 44, 4>(key, plaintext, ciphertext);
+    }
+
+    fn assert_encrypt_decrypt_roundtrip<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >(
+        key: [u8; KEY_SIZE],
+        plaintext: [u8; BLOCK_SIZE],
+        ciphertext: [u8; BLOCK_SIZE],
+    ) {
+        let rc6 = RC6::<
+            WORD_BIT_SIZE,
+            ROUNDS,
+            KEY_SIZE,
+            WORD_SIZE,
+            BLOCK_SIZE,
+            EXPANDED_KEY_TABLE_LEN,
+            KEY_AS_WORDS_LEN,
+        >::new(key);
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc6.encrypt(block),
+            |block| rc6.decrypt(block),
+            plaintext,
+            ciphertext,
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{
+    arx::{join_words, rotation_amount, split_block},
+    words::ByteIntegerExt,
+    RC5,
+};
+
+/// Provides RC5-XOR, a research variant of RC5 in which all additions are replaced by XORs.
+///
+/// See "Improved Cryptanalysis of RC5" by A. Biryukov and E. Kushilevitz. The variant is
+/// considerably weaker than RC5 and only meant for reproducing published attacks.
+pub struct RC5Xor<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    rc5: RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >,
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5Xor<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        Self { rc5: RC5::new(key) }
+    }
+
+    /// Encrypts the plaintext block returning ciphertext block.
+    pub fn encrypt(&self, plaintext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let table = &self.rc5.expanded_key_table;
+        let [mut a, mut b] = split_block(plaintext);
+
+        a = a.bitxor(table[0]);
+        b = b.bitxor(table[1]);
+
+        for idx in 1..=ROUNDS {
+            a = a
+                .bitxor(b)
+                .rotate_left(rotation_amount(b))
+                .bitxor(table[2 * idx]);
+            b = b
+                .bitxor(a)
+                .rotate_left(rotation_amount(a))
+                .bitxor(table[2 * idx + 1]);
+        }
+
+        join_words([a, b])
+    }
+
+    /// Decrypts the ciphertext block returning plaintext block.
+    pub fn decrypt(&self, ciphertext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let table = &self.rc5.expanded_key_table;
+        let [mut a, mut b] = split_block(ciphertext);
+
+        for idx in (1..=ROUNDS).rev() {
+            b = b
+                .bitxor(table[2 * idx + 1])
+                .rotate_right(rotation_amount(a))
+                .bitxor(a);
+            a = a
+                .bitxor(table[2 * idx])
+                .rotate_right(rotation_amount(b))
+                .bitxor(b);
+        }
+
+        b = b.bitxor(table[1]);
+        a = a.bitxor(table[0]);
+
+        join_words([a, b])
+    }
+}
+
+/// Provides RC5P, a research variant of RC5 in which the XORs of the round function are replaced
+/// by additions.
+///
+/// See "Improved Cryptanalysis of RC5" by A. Biryukov and E. Kushilevitz. The variant is
+/// considerably weaker than RC5 and only meant for reproducing published attacks.
+pub struct RC5P<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    rc5: RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >,
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5P<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        Self { rc5: RC5::new(key) }
+    }
+
+    /// Encrypts the plaintext block returning ciphertext block.
+    pub fn encrypt(&self, plaintext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let table = &self.rc5.expanded_key_table;
+        let [mut a, mut b] = split_block(plaintext);
+
+        a = a.wrapping_add(table[0]);
+        b = b.wrapping_add(table[1]);
+
+        for idx in 1..=ROUNDS {
+            a = a
+                .wrapping_add(b)
+                .rotate_left(rotation_amount(b))
+                .wrapping_add(table[2 * idx]);
+            b = b
+                .wrapping_add(a)
+                .rotate_left(rotation_amount(a))
+                .wrapping_add(table[2 * idx + 1]);
+        }
+
+        join_words([a, b])
+    }
+
+    /// Decrypts the ciphertext block returning plaintext block.
+    pub fn decrypt(&self, ciphertext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let table = &self.rc5.expanded_key_table;
+        let [mut a, mut b] = split_block(ciphertext);
+
+        for idx in (1..=ROUNDS).rev() {
+            b = b
+                .wrapping_sub(table[2 * idx + 1])
+                .rotate_right(rotation_amount(a))
+                .wrapping_sub(a);
+            a = a
+                .wrapping_sub(table[2 * idx])
+                .rotate_right(rotation_amount(b))
+                .wrapping_sub(b);
+        }
+
+        b = b.wrapping_sub(table[1]);
+        a = a.wrapping_sub(table[0]);
+
+        join_words([a, b])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arx::test_utils;
+
+    #[test]
+    fn rc_xor_32_12_16_encrypt_decrypt() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0xB9, 0x30, 0x14, 0xA0, 0xBE, 0xEF, 0x98, 0xC1];
+        let rc5 = RC5Xor::<32, 12, 16, 4, 8, 26, 4>::new(key);
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            plaintext,
+            ciphertext,
+        );
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_xor_8_12_4_encrypt_decrypt() {
+        let rc5 = RC5Xor::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            [0x00, 0x01],
+            [0x55, 0xBD],
+        );
+    }
+
+    #[test]
+    fn rc_p_32_12_16_encrypt_decrypt() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+            0x0E, 0x0F,
+        ];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = [0x42, 0x4D, 0xC9, 0x9B, 0x94, 0x32, 0xE1, 0xCC];
+        let rc5 = RC5P::<32, 12, 16, 4, 8, 26, 4>::new(key);
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            plaintext,
+            ciphertext,
+        );
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_p_8_12_4_encrypt_decrypt() {
+        let rc5 = RC5P::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            [0x00, 0x01],
+            [0x77, 0x32],
+        );
+    }
+}
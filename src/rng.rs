@@ -0,0 +1,530 @@
+use rand_core::{
+    block::{BlockRng, BlockRngCore},
+    RngCore, SeedableRng,
+};
+
+use crate::{words::ByteIntegerExt, RC5};
+
+/// Number of keystream bytes produced per [`BlockRngCore::generate`] call.
+const RESULTS_SIZE: usize = 64;
+
+/// Provides a pseudorandom number generator driven by the RC5 keystream in counter mode.
+///
+/// The keystream consists of the encryptions of the counter values 0, 1, 2, ... as little-endian
+/// blocks. The generator is deterministic for a given key, which makes it suitable for reproducible
+/// simulations on devices that already link this crate. It is not meant for generating secrets.
+///
+/// Output is buffered in 64-byte chunks produced by [`RC5RngCore`] through `rand_core`'s
+/// [`BlockRng`].
+///
+/// Example usage:
+/// ```
+/// use rand_core::{RngCore, SeedableRng};
+/// use rc5::RC5Rng;
+///
+/// // RC5-32/12/16
+/// let mut rng = RC5Rng::<32, 12, 16, 4, 8, 26, 4>::from_seed([0x00; 16]);
+/// let mut other = RC5Rng::<32, 12, 16, 4, 8, 26, 4>::from_seed([0x00; 16]);
+///
+/// assert_eq!(rng.next_u64(), other.next_u64());
+/// ```
+pub struct RC5Rng<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    rng: BlockRng<
+        RC5RngCore<
+            WORD_BIT_SIZE,
+            ROUNDS,
+            KEY_SIZE,
+            WORD_SIZE,
+            BLOCK_SIZE,
+            EXPANDED_KEY_TABLE_LEN,
+            KEY_AS_WORDS_LEN,
+        >,
+    >,
+}
+
+/// Produces the keystream of [`RC5Rng`] in 64-byte chunks.
+///
+/// The keystream is continuous across chunks even if the block size does not divide 64.
+pub struct RC5RngCore<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    cipher: RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >,
+    counter: [u8; BLOCK_SIZE],
+    keystream: [u8; BLOCK_SIZE],
+    // Index of the next unused keystream byte, BLOCK_SIZE if the block is used up.
+    keystream_idx: usize,
+    // Number of blocks left before the end of the keystream region of a split stream, or `None` if
+    // the counter may wrap around.
+    blocks_left: Option<[u8; BLOCK_SIZE]>,
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5Rng<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        Self {
+            rng: BlockRng::new(RC5RngCore::new(key)),
+        }
+    }
+
+    /// Skips ahead in the keystream by `n_blocks` cipher blocks, i.e. `n_blocks * BLOCK_SIZE`
+    /// bytes, counted from the next unused output byte.
+    ///
+    /// The counter wraps around if it overflows. Panics if a split stream jumps past the end of its
+    /// keystream region, see [`RC5RngCore::split_stream`].
+    pub fn jump(&mut self, n_blocks: u64) {
+        // Move the core back to the first output word the generator has not handed out yet.
+        let num_results = RESULTS_SIZE / 4;
+        let num_unused_bytes = 4 * (num_results - self.rng.index().min(num_results));
+        self.rng.core.rewind(num_unused_bytes);
+        self.rng.reset();
+
+        self.rng.core.jump(n_blocks);
+    }
+
+    /// Returns a generator with the same key positioned at the start of the keystream region of
+    /// the stream. See [`RC5RngCore::split_stream`].
+    pub fn split_stream(&self, stream_id: u64) -> Self {
+        Self {
+            rng: BlockRng::new(self.rng.core.split_stream(stream_id)),
+        }
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > RngCore
+    for RC5Rng<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.rng.fill_bytes(dst)
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > SeedableRng
+    for RC5Rng<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+where
+    [u8; KEY_SIZE]: Default,
+{
+    type Seed = [u8; KEY_SIZE];
+
+    fn from_seed(seed: [u8; KEY_SIZE]) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5RngCore<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        Self {
+            cipher: RC5::new(key),
+            counter: [0; BLOCK_SIZE],
+            keystream: [0; BLOCK_SIZE],
+            keystream_idx: BLOCK_SIZE,
+            blocks_left: None,
+        }
+    }
+
+    /// Skips ahead in the keystream by `n_blocks` cipher blocks, i.e. `n_blocks * BLOCK_SIZE`
+    /// bytes.
+    ///
+    /// The counter wraps around if it overflows. Panics if a split stream jumps past the end of its
+    /// keystream region, see [`RC5RngCore::split_stream`].
+    pub fn jump(&mut self, n_blocks: u64) {
+        let n_blocks_bytes = n_blocks.to_le_bytes();
+        let n_blocks = <[u8; BLOCK_SIZE]>::from_le_slice(&n_blocks_bytes);
+        if let Some(blocks_left) = &mut self.blocks_left {
+            // Compares the little-endian numbers starting with the most significant byte.
+            assert!(
+                n_blocks_bytes
+                    .iter()
+                    .skip(BLOCK_SIZE)
+                    .all(|&byte| byte == 0)
+                    && !blocks_left.iter().rev().lt(n_blocks.iter().rev()),
+                "Jump crosses the end of the keystream region of the stream."
+            );
+            *blocks_left = blocks_left.wrapping_sub(n_blocks);
+        }
+
+        self.counter = self.counter.wrapping_add(n_blocks);
+        if self.keystream_idx < BLOCK_SIZE {
+            self.reload_keystream();
+        }
+    }
+
+    /// Moves the position in the keystream back by `n_bytes`.
+    fn rewind(&mut self, n_bytes: usize) {
+        if n_bytes <= self.keystream_idx {
+            self.keystream_idx -= n_bytes;
+            return;
+        }
+
+        let n_bytes_before_block = n_bytes - self.keystream_idx;
+        let n_blocks = (n_bytes_before_block - 1) / BLOCK_SIZE + 1;
+        let n_blocks_word = <[u8; BLOCK_SIZE]>::from_le_slice(&n_blocks.to_le_bytes());
+        self.counter = self.counter.wrapping_sub(n_blocks_word);
+        if let Some(blocks_left) = &mut self.blocks_left {
+            *blocks_left = blocks_left.wrapping_add(n_blocks_word);
+        }
+        self.reload_keystream();
+        self.keystream_idx = n_blocks * BLOCK_SIZE - n_bytes_before_block;
+    }
+
+    /// Encrypts the keystream block preceding the counter again.
+    fn reload_keystream(&mut self) {
+        let block = self
+            .counter
+            .wrapping_sub(<[u8; BLOCK_SIZE]>::from_le_slice(&[0x01]));
+        self.keystream = self.cipher.encrypt(block);
+    }
+
+    /// Returns a core with the same key positioned at the start of the keystream region of the
+    /// stream, e.g. to hand out to parallel workers.
+    ///
+    /// The stream ID makes up the upper half of the counter block, so every stream owns a region
+    /// of 2^(4 * BLOCK_SIZE) blocks, e.g. only 2^16 blocks for RC5-16. The returned core panics
+    /// instead of drawing or jumping past the end of its region, so streams with different IDs
+    /// never overlap. Since the keystream is generated in 64-byte chunks, a stream that jumped to a
+    /// position not aligned to a chunk panics up to 64 bytes before the end of its region.
+    ///
+    /// Panics if the stream ID does not fit in half a block.
+    pub fn split_stream(&self, stream_id: u64) -> Self {
+        let half_block_size = BLOCK_SIZE / 2;
+        let stream_id = stream_id.to_le_bytes();
+        assert!(
+            stream_id
+                .iter()
+                .skip(half_block_size)
+                .all(|&byte| byte == 0),
+            "Stream ID does not fit in half a block."
+        );
+
+        let mut counter = [0; BLOCK_SIZE];
+        for (byte, stream_id_byte) in counter[half_block_size..].iter_mut().zip(stream_id) {
+            *byte = stream_id_byte;
+        }
+        let mut blocks_left = [0; BLOCK_SIZE];
+        blocks_left[half_block_size] = 1;
+
+        Self {
+            cipher: self
+                .cipher
+                .with_expanded_key_table(self.cipher.expanded_key_table),
+            counter,
+            keystream: [0; BLOCK_SIZE],
+            keystream_idx: BLOCK_SIZE,
+            blocks_left: Some(blocks_left),
+        }
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > BlockRngCore
+    for RC5RngCore<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    type Item = u32;
+    type Results = [u32; RESULTS_SIZE / 4];
+
+    fn generate(&mut self, results: &mut [u32; RESULTS_SIZE / 4]) {
+        let mut bytes = [0; RESULTS_SIZE];
+        for byte in &mut bytes {
+            if self.keystream_idx == BLOCK_SIZE {
+                if let Some(blocks_left) = &mut self.blocks_left {
+                    assert!(
+                        *blocks_left != [0; BLOCK_SIZE],
+                        "Stream has used up its keystream region."
+                    );
+                    *blocks_left =
+                        blocks_left.wrapping_sub(<[u8; BLOCK_SIZE]>::from_le_slice(&[0x01]));
+                }
+                self.keystream = self.cipher.encrypt(self.counter);
+                self.counter.increment();
+                self.keystream_idx = 0;
+            }
+            *byte = self.keystream[self.keystream_idx];
+            self.keystream_idx += 1;
+        }
+
+        for (result, chunk) in results.iter_mut().zip(bytes.chunks_exact(4)) {
+            *result = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+    }
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    > SeedableRng
+    for RC5RngCore<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+where
+    [u8; KEY_SIZE]: Default,
+{
+    type Seed = [u8; KEY_SIZE];
+
+    fn from_seed(seed: [u8; KEY_SIZE]) -> Self {
+        Self::new(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Expected values are generated by `python3 scripts/reference_vectors.py rng`.
+
+    const KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+        0x0F,
+    ];
+
+    #[test]
+    fn rc_32_12_16_rng_fill_bytes() {
+        let mut rng = RC5Rng::<32, 12, 16, 4, 8, 26, 4>::from_seed(KEY);
+
+        // Each call consumes whole 32-bit words of the keystream.
+        let mut output = [0; 32];
+        rng.fill_bytes(&mut output[..4]);
+        rng.fill_bytes(&mut output[4..20]);
+        rng.fill_bytes(&mut output[20..]);
+        assert_eq!(
+            output,
+            [
+                0xB0, 0x5F, 0x67, 0xED, 0x09, 0x13, 0xB5, 0xA2, 0x1B, 0xCA, 0x80, 0x19, 0x4E, 0x01,
+                0xD9, 0x19, 0x7B, 0xF8, 0xAF, 0x03, 0xB9, 0x37, 0x48, 0x97, 0xEB, 0x8E, 0x0D, 0x29,
+                0xA0, 0xCD, 0xC8, 0x36
+            ]
+        );
+    }
+
+    #[test]
+    fn rc_32_12_16_rng_next() {
+        let mut rng = RC5Rng::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+
+        assert_eq!(rng.next_u32(), 0xED675FB0);
+        assert_eq!(rng.next_u64(), 0x1980CA1BA2B51309);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_24_12_4_rng_keystream_continues_across_chunks() {
+        let mut rng = RC5Rng::<24, 12, 4, 3, 6, 26, 2>::new([0x00, 0x01, 0x02, 0x03]);
+
+        let mut output = [0; 68];
+        rng.fill_bytes(&mut output);
+        assert_eq!(
+            output[60..],
+            [0xFE, 0x0F, 0x0F, 0x84, 0x5A, 0x3E, 0x71, 0xF0]
+        );
+    }
+
+    #[test]
+    fn rc_32_12_16_rng_jump() {
+        let mut rng = RC5Rng::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+
+        rng.next_u32();
+        rng.jump(1);
+        assert_eq!(rng.next_u64(), 0x03AFF87B19D9014E);
+        rng.jump(0);
+        assert_eq!(rng.next_u32(), 0x974837B9);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn rc_24_12_4_rng_jump_within_buffered_output() {
+        let mut rng = RC5Rng::<24, 12, 4, 3, 6, 26, 2>::new([0x00, 0x01, 0x02, 0x03]);
+
+        rng.next_u32();
+        rng.jump(5);
+        let mut output = [0; 8];
+        rng.fill_bytes(&mut output);
+        assert_eq!(output, [0x7B, 0x80, 0xC1, 0xCE, 0xDB, 0x2F, 0x76, 0xAA]);
+    }
+
+    #[test]
+    fn rc_32_12_16_rng_split_stream() {
+        let rng = RC5Rng::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+        let mut stream = rng.split_stream(1);
+
+        let mut output = [0; 16];
+        stream.fill_bytes(&mut output);
+        assert_eq!(
+            output,
+            [
+                0x98, 0x43, 0x95, 0xF3, 0x0D, 0x4E, 0x27, 0xE4, 0x44, 0xBC, 0x91, 0x62, 0xA4, 0x07,
+                0x8B, 0xD3
+            ]
+        );
+
+        let mut stream = rng.split_stream(0);
+        assert_eq!(stream.next_u32(), 0xED675FB0);
+    }
+
+    #[test]
+    fn rc_32_12_16_rng_split_stream_matches_jump() {
+        let mut rng = RC5Rng::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+        let mut stream = rng.split_stream(3);
+
+        rng.jump(3 << 32);
+        assert_eq!(rng.next_u64(), stream.next_u64());
+    }
+
+    #[test]
+    fn rc_32_12_16_rng_split_stream_end_of_region() {
+        let mut rng = RC5Rng::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+        let mut stream = rng.split_stream(1);
+
+        rng.jump((2 << 32) - 8);
+        stream.jump((1 << 32) - 8);
+        let mut output = [0; 64];
+        let mut stream_output = [0; 64];
+        rng.fill_bytes(&mut output);
+        stream.fill_bytes(&mut stream_output);
+        assert_eq!(stream_output, output);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rc_32_12_16_rng_split_stream_draw_past_end_of_region() {
+        let mut stream = RC5Rng::<32, 12, 16, 4, 8, 26, 4>::new(KEY).split_stream(1);
+        stream.jump((1 << 32) - 8);
+        stream.fill_bytes(&mut [0; 64]);
+        stream.next_u32();
+    }
+
+    #[test]
+    #[should_panic]
+    fn rc_32_12_16_rng_split_stream_jump_past_end_of_region() {
+        let mut stream = RC5Rng::<32, 12, 16, 4, 8, 26, 4>::new(KEY).split_stream(1);
+        stream.next_u32();
+        stream.jump(1 << 32);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    #[should_panic]
+    fn rc_8_12_4_rng_split_stream_too_large() {
+        let rng = RC5Rng::<8, 12, 4, 1, 2, 26, 4>::new([0x00, 0x01, 0x02, 0x03]);
+        rng.split_stream(0x100);
+    }
+}
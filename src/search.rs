@@ -0,0 +1,97 @@
+use core::{mem::size_of, ops::Range};
+
+use rayon::prelude::*;
+
+use crate::RC5;
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Searches the key range in parallel for the first key that encrypts the known plaintext to
+    /// the known ciphertext.
+    ///
+    /// Keys are enumerated as little-endian integers, i.e. the key for `n` consists of the
+    /// `KEY_SIZE` low bytes of `n`. This is only practical for small keys (up to ~5 bytes).
+    ///
+    /// Panics if `KEY_SIZE` is larger than 8 bytes. Since the `strict` feature rules out keys
+    /// shorter than 16 bytes at compile time, this cannot be used together with it.
+    pub fn search_key(
+        range: Range<u64>,
+        known_plaintext: [u8; BLOCK_SIZE],
+        known_ciphertext: [u8; BLOCK_SIZE],
+    ) -> Option<[u8; KEY_SIZE]> {
+        assert!(
+            KEY_SIZE <= size_of::<u64>(),
+            "Key search only supports keys up to 8 bytes."
+        );
+
+        range
+            .into_par_iter()
+            .map(|n| {
+                let mut key = [0; KEY_SIZE];
+                key.copy_from_slice(&n.to_le_bytes()[..KEY_SIZE]);
+                key
+            })
+            .find_first(|&key| Self::new(key).encrypt(known_plaintext) == known_ciphertext)
+    }
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_key_rc_32_12_2() {
+        let key = [0xA7, 0x00];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = RC5::<32, 12, 2, 4, 8, 26, 1>::new(key).encrypt(plaintext);
+
+        assert_eq!(
+            RC5::<32, 12, 2, 4, 8, 26, 1>::search_key(0..1 << 8, plaintext, ciphertext),
+            Some(key)
+        );
+    }
+
+    #[test]
+    fn search_key_rc_32_12_4_partial_range() {
+        let key = [0x00, 0x01, 0x02, 0x03];
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = RC5::<32, 12, 4, 4, 8, 26, 1>::new(key).encrypt(plaintext);
+
+        assert_eq!(
+            RC5::<32, 12, 4, 4, 8, 26, 1>::search_key(
+                0x0302_0080..0x0302_0180,
+                plaintext,
+                ciphertext
+            ),
+            Some(key)
+        );
+    }
+
+    #[test]
+    fn search_key_not_found() {
+        let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let ciphertext = RC5::<32, 12, 2, 4, 8, 26, 1>::new([0xA7, 0x3C]).encrypt(plaintext);
+
+        assert_eq!(
+            RC5::<32, 12, 2, 4, 8, 26, 1>::search_key(0..1 << 8, plaintext, ciphertext),
+            None
+        );
+    }
+}
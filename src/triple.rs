@@ -0,0 +1,153 @@
+use crate::RC5;
+
+/// Provides triple RC5, a cascade encrypting with the first key, decrypting with the second key
+/// and encrypting with the third key (EDE).
+///
+/// This mirrors Triple DES and exists to interoperate with legacy systems that strengthened RC5
+/// this way. With the first and second keys equal the cascade reduces to single RC5 under the
+/// third key.
+///
+/// Example usage:
+/// ```
+/// use rc5::TripleRC5;
+///
+/// let key1 = [0x00; 16];
+/// let key2 = [0x01; 16];
+/// let plaintext = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+///
+/// // Two-key triple RC5-32/12/16
+/// let rc5 = TripleRC5::<32, 12, 16, 4, 8, 26, 4>::new_two_key(key1, key2);
+///
+/// assert_eq!(rc5.decrypt(rc5.encrypt(plaintext)), plaintext);
+/// ```
+pub struct TripleRC5<
+    const WORD_BIT_SIZE: usize,
+    const ROUNDS: usize,
+    const KEY_SIZE: usize,
+    const WORD_SIZE: usize,
+    const BLOCK_SIZE: usize,
+    const EXPANDED_KEY_TABLE_LEN: usize,
+    const KEY_AS_WORDS_LEN: usize,
+> {
+    ciphers: [RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >; 3],
+}
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    TripleRC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Creates the cascade from three independent keys.
+    pub fn new(key1: [u8; KEY_SIZE], key2: [u8; KEY_SIZE], key3: [u8; KEY_SIZE]) -> Self {
+        Self {
+            ciphers: [RC5::new(key1), RC5::new(key2), RC5::new(key3)],
+        }
+    }
+
+    /// Creates the cascade from two keys, reusing the first key for the last encryption.
+    pub fn new_two_key(key1: [u8; KEY_SIZE], key2: [u8; KEY_SIZE]) -> Self {
+        Self::new(key1, key2, key1)
+    }
+
+    /// Encrypts the plaintext block returning ciphertext block.
+    pub fn encrypt(&self, plaintext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let [first, second, third] = &self.ciphers;
+        third.encrypt(second.decrypt(first.encrypt(plaintext)))
+    }
+
+    /// Decrypts the ciphertext block returning plaintext block.
+    pub fn decrypt(&self, ciphertext: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        let [first, second, third] = &self.ciphers;
+        first.decrypt(second.encrypt(third.decrypt(ciphertext)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arx::test_utils;
+
+    const KEY1: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+        0x0F,
+    ];
+    const KEY2: [u8; 16] = [
+        0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E,
+        0x1F,
+    ];
+    const KEY3: [u8; 16] = [
+        0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E,
+        0x2F,
+    ];
+    const PLAINTEXT: [u8; 8] = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn triple_rc_32_8_16_encrypt_decrypt() {
+        let rc5 = TripleRC5::<32, 8, 16, 4, 8, 18, 4>::new(KEY1, KEY2, KEY3);
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            PLAINTEXT,
+            [0x7C, 0xE4, 0x1F, 0xC2, 0x3C, 0x64, 0x1A, 0xE4],
+        );
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn triple_rc_32_8_16_encrypt_decrypt_two_key() {
+        let rc5 = TripleRC5::<32, 8, 16, 4, 8, 18, 4>::new_two_key(KEY1, KEY2);
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            PLAINTEXT,
+            [0x33, 0x52, 0xD3, 0xDE, 0x7F, 0xA2, 0x09, 0x2A],
+        );
+    }
+
+    #[test]
+    fn triple_rc_32_12_16_encrypt_decrypt() {
+        let rc5 = TripleRC5::<32, 12, 16, 4, 8, 26, 4>::new(KEY1, KEY2, KEY3);
+
+        test_utils::assert_encrypt_decrypt_roundtrip(
+            |block| rc5.encrypt(block),
+            |block| rc5.decrypt(block),
+            PLAINTEXT,
+            [0x3E, 0xCD, 0xBF, 0xD5, 0x62, 0x0F, 0x81, 0x22],
+        );
+    }
+
+    #[test]
+    fn triple_rc_32_12_16_equal_keys_is_single_rc5() {
+        let rc5 = TripleRC5::<32, 12, 16, 4, 8, 26, 4>::new(KEY1, KEY1, KEY2);
+
+        assert_eq!(
+            rc5.encrypt(PLAINTEXT),
+            RC5::<32, 12, 16, 4, 8, 26, 4>::new(KEY2).encrypt(PLAINTEXT)
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! Ready-made types for the parameter sets listed in the
+//! [RC5 and RC6 test vectors draft](https://datatracker.ietf.org/doc/html/draft-krovetz-rc6-rc5-vectors-00).
+//!
+//! The derived const generics are computed from the word bit size, number of rounds and key size,
+//! so that a documented variant can be referenced by name:
+//!
+//! ```
+//! use rc5::variants::RC5_32_20_16;
+//!
+//! let rc5 = RC5_32_20_16::new([0x00; 16]);
+//! assert_eq!(rc5.decrypt(rc5.encrypt([0x00; 8])), [0x00; 8]);
+//! ```
+
+use crate::{RC5, RC6};
+
+macro_rules! variants {
+    ($($rc5:ident, $rc6:ident => $w:literal / $r:literal / $b:literal;)*) => {
+        $(
+            #[doc = concat!("RC5-", $w, "/", $r, "/", $b)]
+            pub type $rc5 = RC5<
+                $w,
+                $r,
+                $b,
+                { $w / 8 },
+                { 2 * ($w / 8) },
+                { 2 * ($r + 1) },
+                { key_as_words_len($b, $w / 8) },
+            >;
+
+            #[doc = concat!("RC6-", $w, "/", $r, "/", $b)]
+            pub type $rc6 = RC6<
+                $w,
+                $r,
+                $b,
+                { $w / 8 },
+                { 4 * ($w / 8) },
+                { 2 * ($r + 2) },
+                { key_as_words_len($b, $w / 8) },
+            >;
+        )*
+    };
+}
+
+variants! {
+    RC5_8_12_4, RC6_8_12_4 => 8 / 12 / 4;
+    RC5_16_16_8, RC6_16_16_8 => 16 / 16 / 8;
+    RC5_32_20_16, RC6_32_20_16 => 32 / 20 / 16;
+    RC5_64_24_24, RC6_64_24_24 => 64 / 24 / 24;
+    RC5_128_28_32, RC6_128_28_32 => 128 / 28 / 32;
+    RC5_24_4_0, RC6_24_4_0 => 24 / 4 / 0;
+    RC5_80_4_12, RC6_80_4_12 => 80 / 4 / 12;
+}
+
+const fn key_as_words_len(key_size: usize, word_size: usize) -> usize {
+    if key_size == 0 {
+        1
+    } else {
+        (key_size - 1) / word_size + 1
+    }
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rc5_variants_match_explicit_parameters() {
+        assert!(RC5_16_16_8::new([0x00; 8]) == RC5::<16, 16, 8, 2, 4, 34, 4>::new([0x00; 8]));
+        assert!(RC5_64_24_24::new([0x00; 24]) == RC5::<64, 24, 24, 8, 16, 50, 3>::new([0x00; 24]));
+        assert!(
+            RC5_128_28_32::new([0x00; 32]) == RC5::<128, 28, 32, 16, 32, 58, 2>::new([0x00; 32])
+        );
+        assert!(RC5_24_4_0::new([]) == RC5::<24, 4, 0, 3, 6, 10, 1>::new([]));
+        assert!(RC5_80_4_12::new([0x00; 12]) == RC5::<80, 4, 12, 10, 20, 10, 2>::new([0x00; 12]));
+    }
+
+    #[test]
+    fn rc6_variants_match_explicit_parameters() {
+        assert!(RC6_8_12_4::new([0x00; 4]) == RC6::<8, 12, 4, 1, 4, 28, 4>::new([0x00; 4]));
+        assert!(RC6_32_20_16::new([0x00; 16]) == RC6::<32, 20, 16, 4, 16, 44, 4>::new([0x00; 16]));
+        assert!(RC6_24_4_0::new([]) == RC6::<24, 4, 0, 3, 12, 12, 1>::new([]));
+        assert!(RC6_80_4_12::new([0x00; 12]) == RC6::<80, 4, 12, 10, 40, 12, 2>::new([0x00; 12]));
+    }
+
+    // The following test cases are taken from
+    // https://datatracker.ietf.org/doc/html/draft-krovetz-rc6-rc5-vectors-00.
+
+    #[test]
+    fn rc5_8_12_4_variant() {
+        let rc5 = RC5_8_12_4::new([0x00, 0x01, 0x02, 0x03]);
+        assert_eq!(rc5.encrypt([0x00, 0x01]), [0x21, 0x2A]);
+    }
+
+    #[test]
+    fn rc6_16_16_8_variant() {
+        let rc6 = RC6_16_16_8::new([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]);
+        assert_eq!(
+            rc6.encrypt([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]),
+            [0x2F, 0xF0, 0xB6, 0x8E, 0xAE, 0xFF, 0xAD, 0x5B]
+        );
+    }
+}
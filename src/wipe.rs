@@ -0,0 +1,63 @@
+use core::{
+    ptr,
+    sync::atomic::{compiler_fence, Ordering},
+};
+
+use crate::RC5;
+
+impl<
+        const WORD_BIT_SIZE: usize,
+        const ROUNDS: usize,
+        const KEY_SIZE: usize,
+        const WORD_SIZE: usize,
+        const BLOCK_SIZE: usize,
+        const EXPANDED_KEY_TABLE_LEN: usize,
+        const KEY_AS_WORDS_LEN: usize,
+    >
+    RC5<
+        WORD_BIT_SIZE,
+        ROUNDS,
+        KEY_SIZE,
+        WORD_SIZE,
+        BLOCK_SIZE,
+        EXPANDED_KEY_TABLE_LEN,
+        KEY_AS_WORDS_LEN,
+    >
+{
+    /// Zeroes the expanded key table with volatile writes, e.g. before dropping a cipher holding
+    /// key material that must not linger in memory.
+    ///
+    /// The cipher is not wiped automatically when dropped. After wiping, it encrypts and decrypts
+    /// with an all-zero table and must not be used anymore.
+    pub fn wipe(&mut self) {
+        wipe(self.expanded_key_table.iter_mut().flatten());
+    }
+}
+
+/// Zeroes the bytes with volatile writes, which the compiler may not optimize away.
+pub(crate) fn wipe<'a>(bytes: impl IntoIterator<Item = &'a mut u8>) {
+    for byte in bytes {
+        // SAFETY: The pointer comes from a mutable reference, so it is valid and aligned.
+        unsafe { ptr::write_volatile(byte, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wipe_zeroes_key() {
+        let mut key = [0xFF; 16];
+        wipe(&mut key);
+        assert_eq!(key, [0x00; 16]);
+    }
+
+    #[test]
+    fn wipe_zeroes_expanded_key_table() {
+        let mut rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new([0xFF; 16]);
+        rc5.wipe();
+        assert_eq!(rc5.expanded_key_table, [[0x00; 4]; 26]);
+    }
+}
@@ -0,0 +1,1022 @@
+//! Fixed-size little-endian integer operations on byte arrays.
+//!
+//! RC5 and RC6 are defined for arbitrary word sizes, so words are represented as `[u8; N]` arrays
+//! holding an N-byte unsigned integer in little-endian byte order. The [`ByteIntegerExt`] trait
+//! provides the arithmetic on such words and [`BigEndianByteIntegerExt`] its big-endian
+//! counterpart. All operations are `no_std` and do not allocate.
+//!
+//! ```
+//! use rc5::words::ByteIntegerExt;
+//!
+//! let a = <[u8; 3]>::from_le_slice(&[0xFF, 0xFF, 0x00]);
+//! let b = <[u8; 3]>::from_le_slice(&[0x01]);
+//!
+//! assert_eq!(a.wrapping_add(b), [0x00, 0x00, 0x01]);
+//! assert_eq!(a.overflowing_sub(b.shl(16)), ([0xFF, 0xFF, 0xFF], true));
+//! ```
+
+use core::{cmp::min, ops::BitAnd};
+
+/// Operations on N-byte unsigned integers stored in little-endian byte order.
+pub trait ByteIntegerExt: Sized {
+    /// Creates a word from little-endian bytes. Missing bytes are zero and excess bytes (the most
+    /// significant ones) are discarded.
+    fn from_le_slice(s: &[u8]) -> Self;
+    /// Converts the word to little-endian bytes. Words are stored in little-endian byte order, so
+    /// this is the identity, but it makes the byte order explicit at serialization boundaries.
+    fn to_le_bytes(self) -> Self;
+
+    /// Computes the bitwise XOR of the words.
+    fn bitxor(self, rhs: Self) -> Self;
+    /// Rotates the bits to the left.
+    ///
+    /// As in RC5, the rotation amount is taken modulo the largest power of two not exceeding the
+    /// bit size of the word, i.e. only its lg(w) low bits are used.
+    fn rotate_left(self, n: u128) -> Self;
+    /// Rotates the bits to the right. The rotation amount is normalized as in
+    /// [`ByteIntegerExt::rotate_left`].
+    fn rotate_right(self, n: u128) -> Self;
+    /// Shifts the bits to the left, filling the vacated bits with zeros. Shifting by the bit size
+    /// or more yields zero.
+    fn shl(self, n: u128) -> Self;
+    /// Shifts the bits to the right, filling the vacated bits with zeros. Shifting by the bit size
+    /// or more yields zero.
+    fn shr(self, n: u128) -> Self;
+    /// Adds the words modulo 2^(8N).
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Subtracts the words modulo 2^(8N).
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Adds the words, returning the wrapped sum along with whether a carry out of the most
+    /// significant byte occurred.
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    /// Subtracts the words, returning the wrapped difference along with whether a borrow into the
+    /// most significant byte occurred.
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+    /// Multiplies the words modulo 2^(8N).
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Increments the word by one in place, wrapping around to zero.
+    ///
+    /// Stops at the first byte that does not overflow, so it is faster than a full
+    /// [`ByteIntegerExt::wrapping_add`] but its running time depends on the value. Only use it on
+    /// public values such as counters.
+    fn increment(&mut self);
+    /// Compares the words for equality without branching on their contents.
+    fn ct_eq(&self, rhs: &Self) -> Choice;
+    /// Compares the words as unsigned integers without branching on their contents.
+    fn ct_lt(&self, rhs: &Self) -> Choice;
+}
+
+/// The result of a constant-time comparison, either 0 (false) or 1 (true).
+///
+/// Mirrors `subtle::Choice`: the value is only turned into a `bool` at the very end, so that
+/// comparisons of secret-derived words do not branch on the data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Choice(u8);
+
+impl Choice {
+    /// Returns the underlying value, either 0 or 1.
+    pub fn unwrap_u8(self) -> u8 {
+        self.0
+    }
+}
+
+impl From<u8> for Choice {
+    /// Wraps the value, which must be either 0 or 1.
+    fn from(value: u8) -> Choice {
+        debug_assert!(value <= 1, "Choice must be either 0 or 1.");
+        Choice(value)
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Choice;
+
+    fn bitand(self, rhs: Choice) -> Choice {
+        Choice(self.0 & rhs.0)
+    }
+}
+
+impl From<Choice> for bool {
+    fn from(choice: Choice) -> bool {
+        choice.0 != 0
+    }
+}
+
+impl<const N: usize> ByteIntegerExt for [u8; N] {
+    fn from_le_slice(s: &[u8]) -> [u8; N] {
+        let mut output = [0; N];
+
+        let output_len = output.len();
+        let range = ..min(s.len(), output_len);
+        output[range].copy_from_slice(&s[range]);
+
+        output
+    }
+
+    fn to_le_bytes(self) -> [u8; N] {
+        self
+    }
+
+    fn bitxor(self, rhs: [u8; N]) -> [u8; N] {
+        let mut output = [0; N];
+
+        for idx in 0..self.len() {
+            output[idx] = self[idx] ^ rhs[idx];
+        }
+
+        output
+    }
+
+    fn rotate_left(self, n: u128) -> [u8; N] {
+        match normalize_rotation::<N>(n) {
+            0 => self,
+            n => self.shl(n).bitxor(self.shr(num_bits::<N>() - n)),
+        }
+    }
+
+    fn rotate_right(self, n: u128) -> [u8; N] {
+        match normalize_rotation::<N>(n) {
+            0 => self,
+            n => self.shr(n).bitxor(self.shl(num_bits::<N>() - n)),
+        }
+    }
+
+    fn shl(self, n: u128) -> [u8; N] {
+        let mut output = [0; N];
+
+        if n >= num_bits::<N>() {
+            return output;
+        }
+        let byte_shift = n as usize / 8;
+        let bit_shift = n as usize % 8;
+
+        for (idx, output_byte) in output.iter_mut().enumerate().skip(byte_shift) {
+            let src_idx = idx - byte_shift;
+            *output_byte = self[src_idx] << bit_shift;
+            if bit_shift > 0 && src_idx > 0 {
+                *output_byte |= self[src_idx - 1] >> (8 - bit_shift);
+            }
+        }
+
+        output
+    }
+
+    fn shr(self, n: u128) -> [u8; N] {
+        let mut output = [0; N];
+
+        if n >= num_bits::<N>() {
+            return output;
+        }
+        let byte_shift = n as usize / 8;
+        let bit_shift = n as usize % 8;
+
+        for (idx, output_byte) in output.iter_mut().enumerate().take(N - byte_shift) {
+            let src_idx = idx + byte_shift;
+            *output_byte = self[src_idx] >> bit_shift;
+            if bit_shift > 0 && src_idx + 1 < N {
+                *output_byte |= self[src_idx + 1] << (8 - bit_shift);
+            }
+        }
+
+        output
+    }
+
+    fn wrapping_add(self, rhs: [u8; N]) -> [u8; N] {
+        self.overflowing_add(rhs).0
+    }
+
+    fn wrapping_sub(self, rhs: [u8; N]) -> [u8; N] {
+        self.overflowing_sub(rhs).0
+    }
+
+    fn overflowing_add(self, rhs: [u8; N]) -> ([u8; N], bool) {
+        let mut output = [0; N];
+
+        let mut carry = 0;
+        for idx in (0..N).step_by(LIMB_SIZE) {
+            let temp_sum: u128 =
+                load_limb(&self, idx) as u128 + load_limb(&rhs, idx) as u128 + carry;
+            // The carry is taken past the last byte of a partial limb.
+            carry = temp_sum >> (8 * limb_len::<N>(idx));
+            store_limb(&mut output, idx, temp_sum as u64);
+        }
+
+        (output, carry > 0)
+    }
+
+    fn overflowing_sub(self, rhs: [u8; N]) -> ([u8; N], bool) {
+        let mut output = [0; N];
+
+        let mut borrow = 0;
+        for idx in (0..N).step_by(LIMB_SIZE) {
+            let temp_diff: u128 = (load_limb(&self, idx) as u128)
+                .wrapping_sub(load_limb(&rhs, idx) as u128)
+                .wrapping_sub(borrow);
+            borrow = temp_diff >> 127;
+            store_limb(&mut output, idx, temp_diff as u64);
+        }
+
+        (output, borrow > 0)
+    }
+
+    fn wrapping_mul(self, rhs: [u8; N]) -> [u8; N] {
+        let mut output = [0; N];
+
+        // Schoolbook multiplication over limbs. The product of two limbs plus the carry and the
+        // accumulated output limb always fits into a u128.
+        for lhs_idx in (0..N).step_by(LIMB_SIZE) {
+            let lhs_limb = load_limb(&self, lhs_idx) as u128;
+            if lhs_limb == 0 {
+                continue;
+            }
+
+            let mut carry: u128 = 0;
+            // Products landing beyond the last byte are discarded.
+            for rhs_idx in (0..N - lhs_idx).step_by(LIMB_SIZE) {
+                let out_idx = lhs_idx + rhs_idx;
+                let temp_prod: u128 = load_limb(&output, out_idx) as u128
+                    + lhs_limb * load_limb(&rhs, rhs_idx) as u128
+                    + carry;
+                carry = temp_prod >> 64;
+                store_limb(&mut output, out_idx, temp_prod as u64);
+            }
+        }
+
+        output
+    }
+
+    fn increment(&mut self) {
+        for byte in self.iter_mut() {
+            *byte = byte.wrapping_add(1);
+            if *byte != 0 {
+                break;
+            }
+        }
+    }
+
+    fn ct_eq(&self, rhs: &[u8; N]) -> Choice {
+        let mut diff = 0;
+        for idx in 0..self.len() {
+            diff |= self[idx] ^ rhs[idx];
+        }
+
+        // 0 underflows to all ones, any other byte value leaves the high byte clear.
+        Choice(((diff as u16).wrapping_sub(1) >> 8) as u8 & 1)
+    }
+
+    fn ct_lt(&self, rhs: &[u8; N]) -> Choice {
+        // The final borrow of self - rhs is set exactly when self < rhs.
+        let mut borrow: u16 = 0;
+        for idx in 0..self.len() {
+            let temp_diff = (self[idx] as u16)
+                .wrapping_sub(rhs[idx] as u16)
+                .wrapping_sub(borrow);
+            borrow = temp_diff >> 15;
+        }
+
+        Choice(borrow as u8)
+    }
+}
+
+/// Words are processed in machine-word sized limbs. Words whose size is not a multiple of the limb
+/// size end with a partial limb.
+const LIMB_SIZE: usize = 8;
+
+/// Returns the number of bytes of the limb starting at byte `idx`.
+fn limb_len<const N: usize>(idx: usize) -> usize {
+    min(LIMB_SIZE, N - idx)
+}
+
+/// Reads a little-endian limb starting at byte `idx`, padding bytes past the end with zeros.
+fn load_limb<const N: usize>(value: &[u8; N], idx: usize) -> u64 {
+    let mut limb = [0; LIMB_SIZE];
+    let len = limb_len::<N>(idx);
+    limb[..len].copy_from_slice(&value[idx..idx + len]);
+    u64::from_le_bytes(limb)
+}
+
+/// Writes a little-endian limb starting at byte `idx`, discarding bytes past the end.
+fn store_limb<const N: usize>(value: &mut [u8; N], idx: usize, limb: u64) {
+    let len = limb_len::<N>(idx);
+    value[idx..idx + len].copy_from_slice(&limb.to_le_bytes()[..len]);
+}
+
+/// Returns the reduction polynomial used by [`gf_double`] for the block bit size without its
+/// leading term, or `None` if the block bit size is not supported.
+pub(crate) const fn gf_polynomial(block_bit_size: usize) -> Option<u32> {
+    match block_bit_size {
+        16 => Some(0x2B),
+        32 => Some(0x8D),
+        64 => Some(0x1B),
+        128 => Some(0x87),
+        256 => Some(0x425),
+        512 => Some(0x125),
+        1024 => Some(0x80043),
+        _ => None,
+    }
+}
+
+/// Multiplies the little-endian block by x in GF(2^n), where n is the block bit size.
+///
+/// The block is shifted left by one bit and, if the most significant bit was set, reduced by the
+/// lexicographically first irreducible polynomial of minimal weight for the block size (e.g.
+/// x^128 + x^7 + x^2 + x + 1 for 128-bit blocks), as used by CMAC and related modes.
+///
+/// Panics if the block bit size is not a power of two between 16 and 1024.
+pub fn gf_double<const N: usize>(block: [u8; N]) -> [u8; N] {
+    let Some(polynomial) = gf_polynomial(N * 8) else {
+        panic!("Unsupported block size for GF(2^n) doubling.");
+    };
+
+    let carry = block[N - 1] >> 7;
+    let mut output = block.shl(1);
+    // Constant-time conditional reduction: the mask is either all zeros or all ones.
+    let mask = carry.wrapping_neg();
+    for (output_byte, polynomial_byte) in output.iter_mut().zip(polynomial.to_le_bytes()) {
+        *output_byte ^= polynomial_byte & mask;
+    }
+
+    output
+}
+
+/// Big-endian counterpart of [`ByteIntegerExt`].
+///
+/// Provides conversions between big-endian bytes and the little-endian words used throughout the
+/// crate, as well as arithmetic on integers stored in big-endian byte order (most significant byte
+/// first).
+pub trait BigEndianByteIntegerExt {
+    /// Creates a little-endian word from big-endian bytes. Missing bytes are zero and excess bytes
+    /// (the most significant ones) are discarded.
+    fn from_be_slice(s: &[u8]) -> Self;
+    /// Converts a little-endian word to big-endian bytes.
+    fn to_be_bytes(self) -> Self;
+
+    /// Rotates the bits of a big-endian integer to the left. The rotation amount is normalized as
+    /// in [`ByteIntegerExt::rotate_left`].
+    fn rotate_left_be(self, n: u128) -> Self;
+    /// Rotates the bits of a big-endian integer to the right. The rotation amount is normalized as
+    /// in [`ByteIntegerExt::rotate_left`].
+    fn rotate_right_be(self, n: u128) -> Self;
+    /// Adds big-endian integers modulo 2^(8N).
+    fn wrapping_add_be(self, rhs: Self) -> Self;
+    /// Subtracts big-endian integers modulo 2^(8N).
+    fn wrapping_sub_be(self, rhs: Self) -> Self;
+}
+
+impl<const N: usize> BigEndianByteIntegerExt for [u8; N] {
+    fn from_be_slice(s: &[u8]) -> [u8; N] {
+        let mut output = [0; N];
+
+        for (dest, src) in output.iter_mut().zip(s.iter().rev()) {
+            *dest = *src;
+        }
+
+        output
+    }
+
+    fn to_be_bytes(self) -> [u8; N] {
+        reverse(self)
+    }
+
+    fn rotate_left_be(self, n: u128) -> [u8; N] {
+        reverse(reverse(self).rotate_left(n))
+    }
+
+    fn rotate_right_be(self, n: u128) -> [u8; N] {
+        reverse(reverse(self).rotate_right(n))
+    }
+
+    fn wrapping_add_be(self, rhs: [u8; N]) -> [u8; N] {
+        reverse(reverse(self).wrapping_add(reverse(rhs)))
+    }
+
+    fn wrapping_sub_be(self, rhs: [u8; N]) -> [u8; N] {
+        reverse(reverse(self).wrapping_sub(reverse(rhs)))
+    }
+}
+
+fn reverse<const N: usize>(mut value: [u8; N]) -> [u8; N] {
+    value.reverse();
+    value
+}
+
+/// Returns the bit size of an N-byte word, computed in u128 so that it cannot overflow a 16-bit
+/// `usize`.
+fn num_bits<const N: usize>() -> u128 {
+    N as u128 * 8
+}
+
+/// Normalizes the rotation amount to a value between 0 and the word bit size - 1 by taking it
+/// modulo the largest power of two not exceeding the word bit size.
+fn normalize_rotation<const N: usize>(n: u128) -> u128 {
+    let mut num_bits = num_bits::<N>();
+    if !u128::is_power_of_two(num_bits) {
+        num_bits = u128::next_power_of_two(num_bits) >> 1;
+    }
+    n % num_bits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_le_slice_2_a() {
+        assert_eq!(<[u8; 2]>::from_le_slice(&[0x01]), [0x01, 0x00]);
+    }
+
+    #[test]
+    fn from_le_slice_2_b() {
+        assert_eq!(<[u8; 2]>::from_le_slice(&[0x01, 0x02]), [0x01, 0x02]);
+    }
+
+    #[test]
+    fn from_le_slice_2_c() {
+        assert_eq!(<[u8; 2]>::from_le_slice(&[0x01, 0x02, 0x03]), [0x01, 0x02]);
+    }
+
+    #[test]
+    fn to_le_bytes_4() {
+        assert_eq!(
+            [0x01, 0x02, 0x03, 0x04].to_le_bytes(),
+            [0x01, 0x02, 0x03, 0x04]
+        );
+    }
+
+    #[test]
+    fn rotate_left_1_a() {
+        assert_rotate_left([0b0000_0001], 1, [0b0000_0010]);
+    }
+
+    #[test]
+    fn rotate_left_1_b() {
+        assert_rotate_left([0b1000_0000], 1, [0b0000_0001]);
+    }
+
+    #[test]
+    fn rotate_left_1_c() {
+        assert_rotate_left([0b0000_0001], 2, [0b0000_0100]);
+    }
+
+    #[test]
+    fn rotate_left_1_d() {
+        assert_rotate_left([0b1100_0011], 10, [0b0000_1111]);
+    }
+
+    #[test]
+    fn rotate_left_2_a() {
+        assert_rotate_left([0b0000_0001, 0b0000_0001], 1, [0b0000_0010, 0b0000_0010]);
+    }
+
+    #[test]
+    fn rotate_left_2_b() {
+        assert_rotate_left([0b1000_0000, 0b1000_0000], 1, [0b0000_0001, 0b0000_0001]);
+    }
+
+    #[test]
+    fn rotate_left_2_c() {
+        assert_rotate_left([0b0000_0001, 0b0000_0001], 2, [0b0000_0100, 0b0000_0100]);
+    }
+
+    #[test]
+    fn rotate_left_3_a() {
+        assert_rotate_left([0x8D, 0x0A, 0xBF], 12520077, [0xE1, 0xB7, 0x51]);
+    }
+
+    #[test]
+    fn rotate_left_3_b() {
+        assert_rotate_left([0xD8, 0x43, 0xC7], 2272123, [0x3A, 0xC6, 0x1E]);
+    }
+
+    #[test]
+    fn rotate_left_4_a() {
+        assert_rotate_left([87, 178, 252, 72], 1335181668, [116, 37, 203, 143]);
+    }
+
+    #[test]
+    fn rotate_left_4_b() {
+        assert_rotate_left([233, 70, 93, 91], 348653453, [107, 43, 221, 168]);
+    }
+
+    #[test]
+    fn rotate_right_1_a() {
+        assert_rotate_right([0b0000_0001], 0b0000_0001, [0b1000_0000]);
+    }
+
+    #[test]
+    fn rotate_right_1_b() {
+        assert_rotate_right([0b1000_0000], 0b0000_0001, [0b0100_0000]);
+    }
+
+    #[test]
+    fn shl_2_a() {
+        assert_eq!(
+            [0b1000_0001, 0b0000_0001].shl(1),
+            [0b0000_0010, 0b0000_0011]
+        );
+    }
+
+    #[test]
+    fn shl_2_b() {
+        assert_eq!([0x01, 0x02].shl(16), [0x00, 0x00]);
+    }
+
+    #[test]
+    fn shr_2_a() {
+        assert_eq!(
+            [0b0000_0010, 0b0000_0011].shr(1),
+            [0b1000_0001, 0b0000_0001]
+        );
+    }
+
+    #[test]
+    fn shr_2_b() {
+        assert_eq!([0x01, 0x02].shr(u128::MAX), [0x00, 0x00]);
+    }
+
+    #[test]
+    fn shift_1_against_u128() {
+        for value in 0..=u8::MAX as u128 {
+            assert_shift_against_u128::<1>(value);
+        }
+    }
+
+    #[test]
+    fn shift_2_against_u128() {
+        for value in (0..=u16::MAX as u128).step_by(3) {
+            assert_shift_against_u128::<2>(value);
+        }
+    }
+
+    #[test]
+    fn shift_3_against_u128() {
+        for value in (0..1 << 24).step_by(997) {
+            assert_shift_against_u128::<3>(value);
+        }
+    }
+
+    #[test]
+    fn shift_16_against_u128() {
+        assert_shift_against_u128::<16>(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+        assert_shift_against_u128::<16>(u128::MAX);
+    }
+
+    #[test]
+    fn gf_double_128_rfc_4493() {
+        // CMAC subkeys of RFC 4493, section 4. The values are big-endian.
+        let l = [
+            0x7D, 0xF7, 0x6B, 0x0C, 0x1A, 0xB8, 0x99, 0xB3, 0x3E, 0x42, 0xF0, 0x47, 0xB9, 0x1B,
+            0x54, 0x6F,
+        ];
+        let k1 = [
+            0xFB, 0xEE, 0xD6, 0x18, 0x35, 0x71, 0x33, 0x66, 0x7C, 0x85, 0xE0, 0x8F, 0x72, 0x36,
+            0xA8, 0xDE,
+        ];
+        let k2 = [
+            0xF7, 0xDD, 0xAC, 0x30, 0x6A, 0xE2, 0x66, 0xCC, 0xF9, 0x0B, 0xC1, 0x1E, 0xE4, 0x6D,
+            0x51, 0x3B,
+        ];
+
+        assert_eq!(gf_double(reverse(l)), reverse(k1));
+        assert_eq!(gf_double(reverse(k1)), reverse(k2));
+    }
+
+    #[test]
+    fn gf_double_64_reduce() {
+        assert_eq!(
+            gf_double([0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80]),
+            [0x1B ^ 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn gf_double_256_no_reduce() {
+        let mut block = [0; 32];
+        block[31] = 0x40;
+        let mut expected = [0; 32];
+        expected[31] = 0x80;
+
+        assert_eq!(gf_double(block), expected);
+    }
+
+    #[test]
+    fn gf_double_1024_reduce() {
+        let mut block = [0; 128];
+        block[127] = 0x80;
+        let mut expected = [0; 128];
+        expected[..3].copy_from_slice(&[0x43, 0x00, 0x08]);
+
+        assert_eq!(gf_double(block), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gf_double_24_unsupported() {
+        gf_double([0x00; 3]);
+    }
+
+    #[test]
+    fn increment_3() {
+        assert_increment([0x00, 0x00, 0x00], [0x01, 0x00, 0x00]);
+        assert_increment([0xFF, 0x00, 0x00], [0x00, 0x01, 0x00]);
+        assert_increment([0xFF, 0xFF, 0x7F], [0x00, 0x00, 0x80]);
+        assert_increment([0xFF, 0xFF, 0xFF], [0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn increment_2_against_wrapping_add() {
+        for value in 0..=u16::MAX {
+            let mut word = value.to_le_bytes();
+            word.increment();
+            assert_eq!(word, value.to_le_bytes().wrapping_add([0x01, 0x00]));
+        }
+    }
+
+    #[test]
+    fn choice_bitand() {
+        for (a, b) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            assert_eq!((Choice::from(a) & Choice::from(b)).unwrap_u8(), a & b);
+        }
+    }
+
+    #[test]
+    fn ct_eq_4() {
+        assert!(bool::from(
+            [0x01, 0x02, 0x03, 0x04].ct_eq(&[0x01, 0x02, 0x03, 0x04])
+        ));
+        assert!(!bool::from(
+            [0x01, 0x02, 0x03, 0x04].ct_eq(&[0x01, 0x02, 0x03, 0x05])
+        ));
+        assert!(!bool::from(
+            [0x00, 0x00, 0x00, 0x00].ct_eq(&[0x80, 0x00, 0x00, 0x00])
+        ));
+    }
+
+    #[test]
+    fn ct_lt_4() {
+        assert!(bool::from(
+            [0xFF, 0x00, 0x00, 0x00].ct_lt(&[0x00, 0x01, 0x00, 0x00])
+        ));
+        assert!(!bool::from(
+            [0x00, 0x01, 0x00, 0x00].ct_lt(&[0xFF, 0x00, 0x00, 0x00])
+        ));
+        assert!(!bool::from(
+            [0x01, 0x02, 0x03, 0x04].ct_lt(&[0x01, 0x02, 0x03, 0x04])
+        ));
+    }
+
+    #[test]
+    fn ct_cmp_2_exhaustive_rhs() {
+        let lhs: u16 = 0x80FF;
+        for rhs in 0..=u16::MAX {
+            let (lhs_word, rhs_word) = (lhs.to_le_bytes(), rhs.to_le_bytes());
+            assert_eq!(lhs_word.ct_eq(&rhs_word).unwrap_u8(), (lhs == rhs) as u8);
+            assert_eq!(lhs_word.ct_lt(&rhs_word).unwrap_u8(), (lhs < rhs) as u8);
+        }
+    }
+
+    #[test]
+    fn wrapping_add_1_a() {
+        assert_wrapping_add([0x01], [0x01], [0x02]);
+    }
+
+    #[test]
+    fn wrapping_add_1_b() {
+        assert_wrapping_add([0xFF], [0x01], [0x00]);
+    }
+
+    #[test]
+    fn wrapping_add_2_a() {
+        assert_wrapping_add([0x00, 0x01], [0x01, 0x00], [0x01, 0x01]);
+    }
+
+    #[test]
+    fn wrapping_add_2_b() {
+        assert_wrapping_add([0xFF, 0xFF], [0x01, 0x00], [0x00, 0x00]);
+    }
+
+    #[test]
+    fn wrapping_add_3_a() {
+        assert_wrapping_add([0x00, 0x01, 0x02], [0x02, 0x01, 0x00], [0x02, 0x02, 0x02]);
+    }
+
+    #[test]
+    fn wrapping_add_3_b() {
+        assert_wrapping_add([0xFF, 0xFF, 0xFF], [0x01, 0x00, 0x00], [0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn wrapping_add_4_a() {
+        assert_wrapping_add([0, 17, 34, 51], [51, 226, 71, 212], [51, 243, 105, 7]);
+    }
+
+    #[test]
+    fn wrapping_add_4_b() {
+        assert_wrapping_add([68, 85, 102, 119], [32, 236, 46, 216], [100, 65, 149, 79]);
+    }
+
+    #[test]
+    fn wrapping_sub_1_a() {
+        assert_wrapping_sub([0x01], [0x01], [0x00]);
+    }
+
+    #[test]
+    fn wrapping_sub_1_b() {
+        assert_wrapping_sub([0x00], [0x01], [0xFF]);
+    }
+
+    #[test]
+    fn wrapping_sub_2_a() {
+        assert_wrapping_sub([0x01, 0x00], [0x00, 0x01], [0x01, 0xFF]);
+    }
+
+    #[test]
+    fn wrapping_sub_2_b() {
+        assert_wrapping_sub([0x00, 0x00], [0x01, 0x00], [0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn wrapping_sub_4_a() {
+        assert_wrapping_sub([207, 8, 139, 158], [6, 226, 232, 21], [201, 38, 162, 136]);
+    }
+
+    #[test]
+    fn overflowing_add_2() {
+        assert_eq!(
+            [0xFF, 0x7F].overflowing_add([0x01, 0x00]),
+            ([0x00, 0x80], false)
+        );
+        assert_eq!(
+            [0xFF, 0xFF].overflowing_add([0x01, 0x00]),
+            ([0x00, 0x00], true)
+        );
+        assert_eq!(
+            [0xFE, 0xFF].overflowing_add([0xFF, 0xFF]),
+            ([0xFD, 0xFF], true)
+        );
+    }
+
+    #[test]
+    fn overflowing_sub_2() {
+        assert_eq!(
+            [0x00, 0x80].overflowing_sub([0x01, 0x00]),
+            ([0xFF, 0x7F], false)
+        );
+        assert_eq!(
+            [0x00, 0x00].overflowing_sub([0x01, 0x00]),
+            ([0xFF, 0xFF], true)
+        );
+        assert_eq!(
+            [0x01, 0x00].overflowing_sub([0x01, 0x00]),
+            ([0x00, 0x00], false)
+        );
+    }
+
+    #[test]
+    fn overflowing_add_sub_3_against_u128() {
+        assert_overflowing_add_sub_against_u128::<3>();
+    }
+
+    #[test]
+    fn overflowing_add_sub_8_against_u128() {
+        assert_overflowing_add_sub_against_u128::<8>();
+    }
+
+    #[test]
+    fn overflowing_add_sub_11_against_u128() {
+        assert_overflowing_add_sub_against_u128::<11>();
+    }
+
+    #[test]
+    fn overflowing_add_sub_16_against_u128() {
+        assert_overflowing_add_sub_against_u128::<16>();
+    }
+
+    #[test]
+    fn rotate_13_against_u128() {
+        let value: u128 = 0x00AB_CDEF_0123_4567_89AB_CDEF_1357;
+        let word = <[u8; 13]>::from_le_slice(&value.to_le_bytes());
+        let mask = u128::MAX >> (128 - 104);
+
+        // Rotation amounts are taken modulo 64 for 104-bit words.
+        for n in 0..200 {
+            let n_normalized = n % 64;
+            let expected_left = ((value << n_normalized) | (value >> (104 - n_normalized))) & mask;
+            let expected_right = ((value >> n_normalized) | (value << (104 - n_normalized))) & mask;
+            assert_eq!(
+                word.rotate_left(n),
+                <[u8; 13]>::from_le_slice(&expected_left.to_le_bytes()),
+                "{n}"
+            );
+            assert_eq!(
+                word.rotate_right(n),
+                <[u8; 13]>::from_le_slice(&expected_right.to_le_bytes()),
+                "{n}"
+            );
+        }
+    }
+
+    #[test]
+    fn wrapping_mul_1_a() {
+        assert_wrapping_mul([0x03], [0x05], [0x0F]);
+    }
+
+    #[test]
+    fn wrapping_mul_1_b() {
+        assert_wrapping_mul([0xFF], [0xFF], [0x01]);
+    }
+
+    #[test]
+    fn wrapping_mul_2_a() {
+        assert_wrapping_mul([0xFF, 0x00], [0xFF, 0x00], [0x01, 0xFE]);
+    }
+
+    #[test]
+    fn wrapping_mul_2_b() {
+        assert_wrapping_mul([0xFF, 0xFF], [0xFF, 0xFF], [0x01, 0x00]);
+    }
+
+    #[test]
+    fn wrapping_mul_4_a() {
+        assert_wrapping_mul([87, 178, 252, 72], [233, 70, 93, 91], [47, 27, 93, 61]);
+    }
+
+    #[test]
+    fn wrapping_mul_3_against_u128() {
+        assert_wrapping_mul_against_u128::<3>();
+    }
+
+    #[test]
+    fn wrapping_mul_5_against_u128() {
+        assert_wrapping_mul_against_u128::<5>();
+    }
+
+    #[test]
+    fn wrapping_mul_9_against_u128() {
+        assert_wrapping_mul_against_u128::<9>();
+    }
+
+    #[test]
+    fn wrapping_mul_16_against_u128() {
+        assert_wrapping_mul_against_u128::<16>();
+    }
+
+    #[test]
+    fn from_be_slice_2_a() {
+        assert_eq!(<[u8; 2]>::from_be_slice(&[0x01]), [0x01, 0x00]);
+    }
+
+    #[test]
+    fn from_be_slice_2_b() {
+        assert_eq!(<[u8; 2]>::from_be_slice(&[0x01, 0x02]), [0x02, 0x01]);
+    }
+
+    #[test]
+    fn from_be_slice_2_c() {
+        assert_eq!(<[u8; 2]>::from_be_slice(&[0x01, 0x02, 0x03]), [0x03, 0x02]);
+    }
+
+    #[test]
+    fn to_be_bytes_4() {
+        assert_eq!(
+            [0x01, 0x02, 0x03, 0x04].to_be_bytes(),
+            [0x04, 0x03, 0x02, 0x01]
+        );
+    }
+
+    #[test]
+    fn rotate_left_be_2() {
+        assert_eq!(
+            [0b1000_0000, 0b0000_0001].rotate_left_be(1),
+            [0b0000_0000, 0b0000_0011]
+        );
+    }
+
+    #[test]
+    fn rotate_right_be_2() {
+        assert_eq!(
+            [0b0000_0000, 0b0000_0011].rotate_right_be(1),
+            [0b1000_0000, 0b0000_0001]
+        );
+    }
+
+    #[test]
+    fn wrapping_add_be_2() {
+        assert_eq!([0x00, 0xFF].wrapping_add_be([0x00, 0x01]), [0x01, 0x00]);
+        assert_eq!([0xFF, 0xFF].wrapping_add_be([0x00, 0x01]), [0x00, 0x00]);
+    }
+
+    #[test]
+    fn wrapping_sub_be_2() {
+        assert_eq!([0x01, 0x00].wrapping_sub_be([0x00, 0x01]), [0x00, 0xFF]);
+        assert_eq!([0x00, 0x00].wrapping_sub_be([0x00, 0x01]), [0xFF, 0xFF]);
+    }
+
+    fn assert_rotate_left<const N: usize>(value: [u8; N], n: u128, expected: [u8; N]) {
+        let output = value.rotate_left(n);
+        assert_eq!(output, expected);
+    }
+
+    fn assert_rotate_right<const N: usize>(value: [u8; N], n: u128, expected: [u8; N]) {
+        let output = value.rotate_right(n);
+        assert_eq!(output, expected);
+    }
+
+    fn assert_shift_against_u128<const N: usize>(value: u128) {
+        let num_bits = N as u128 * 8;
+        let mask = u128::MAX >> (128 - num_bits);
+        let word = <[u8; N]>::from_le_slice(&value.to_le_bytes());
+
+        for n in 0..=num_bits + 1 {
+            let (expected_shl, expected_shr) = if n >= num_bits {
+                (0, 0)
+            } else {
+                ((value << n) & mask, value >> n)
+            };
+            assert_eq!(
+                word.shl(n),
+                <[u8; N]>::from_le_slice(&expected_shl.to_le_bytes()),
+                "{value:#x} << {n}"
+            );
+            assert_eq!(
+                word.shr(n),
+                <[u8; N]>::from_le_slice(&expected_shr.to_le_bytes()),
+                "{value:#x} >> {n}"
+            );
+        }
+    }
+
+    fn assert_wrapping_add<const N: usize>(lhs: [u8; N], rhs: [u8; N], expected: [u8; N]) {
+        let output = lhs.wrapping_add(rhs);
+        assert_eq!(output, expected);
+    }
+
+    fn assert_wrapping_sub<const N: usize>(lhs: [u8; N], rhs: [u8; N], expected: [u8; N]) {
+        let output = lhs.wrapping_sub(rhs);
+        assert_eq!(output, expected);
+    }
+
+    fn assert_overflowing_add_sub_against_u128<const N: usize>() {
+        let num_bits = N as u32 * 8;
+        let modulus_mask = u128::MAX >> (128 - num_bits);
+
+        let mut state: u128 = 0x2545_F491_4F6C_DD1D;
+        for _ in 0..1000 {
+            state = state
+                .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+                .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+            let lhs = state & modulus_mask;
+            let rhs = state.rotate_left(61) & modulus_mask;
+
+            let (sum, carry) = lhs.overflowing_add(rhs);
+            let expected_sum = (
+                <[u8; N]>::from_le_slice(&(sum & modulus_mask).to_le_bytes()),
+                carry || sum > modulus_mask,
+            );
+            let expected_diff = (
+                <[u8; N]>::from_le_slice(&lhs.wrapping_sub(rhs).to_le_bytes()),
+                lhs < rhs,
+            );
+
+            let (lhs_word, rhs_word) = (
+                <[u8; N]>::from_le_slice(&lhs.to_le_bytes()),
+                <[u8; N]>::from_le_slice(&rhs.to_le_bytes()),
+            );
+            assert_eq!(lhs_word.overflowing_add(rhs_word), expected_sum);
+            assert_eq!(lhs_word.overflowing_sub(rhs_word), expected_diff);
+        }
+    }
+
+    fn assert_wrapping_mul_against_u128<const N: usize>() {
+        // Simple LCG to cover a spread of operands. Every other left operand has zero limbs.
+        let mut state: u128 = 0x2545_F491_4F6C_DD1D;
+        for idx in 0..1000 {
+            state = state
+                .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+                .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+            let lhs = if idx % 2 == 0 {
+                state
+            } else {
+                state & 0xFFFF_FFFF_0000_0000_FFFF_FFFF_0000_0000
+            };
+            let rhs = state.rotate_left(37);
+
+            let expected = lhs.wrapping_mul(rhs);
+            assert_wrapping_mul::<N>(
+                <[u8; N]>::from_le_slice(&lhs.to_le_bytes()),
+                <[u8; N]>::from_le_slice(&rhs.to_le_bytes()),
+                <[u8; N]>::from_le_slice(&expected.to_le_bytes()),
+            );
+        }
+    }
+
+    fn assert_increment<const N: usize>(mut word: [u8; N], expected: [u8; N]) {
+        word.increment();
+        assert_eq!(word, expected);
+    }
+
+    fn assert_wrapping_mul<const N: usize>(lhs: [u8; N], rhs: [u8; N], expected: [u8; N]) {
+        let output = lhs.wrapping_mul(rhs);
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,69 @@
+//! Ensures the core ciphers never allocate on the heap.
+//!
+//! Installs a global allocator that counts the allocations made by the current thread, so that the
+//! allocations of the test harness running on other threads do not interfere.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use rc5::{RC5, RC6};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn rc5_does_not_allocate() {
+    let allocations = count_allocations(|| {
+        let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new([0x00; 16]);
+        let ciphertext = rc5.encrypt([0x00; 8]);
+        assert_eq!(rc5.decrypt(ciphertext), [0x00; 8]);
+
+        let rc5 = RC5::<128, 28, 32, 16, 32, 58, 2>::new([0x00; 32]);
+        let ciphertext = rc5.encrypt([0x00; 32]);
+        assert_eq!(rc5.decrypt(ciphertext), [0x00; 32]);
+    });
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn rc6_does_not_allocate() {
+    let allocations = count_allocations(|| {
+        let rc6 = RC6::<32, 20, 16, 4, 16, 44, 4>::new([0x00; 16]);
+        let ciphertext = rc6.encrypt([0x00; 16]);
+        assert_eq!(rc6.decrypt(ciphertext), [0x00; 16]);
+    });
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn counting_allocator_detects_allocations() {
+    let allocations = count_allocations(|| {
+        std::hint::black_box(Box::new(0u8));
+    });
+    assert_eq!(allocations, 1);
+}
@@ -0,0 +1,47 @@
+//! Ensures the `strict` feature rejects weak keys at runtime while allowing strong parameters.
+
+#![cfg(feature = "strict")]
+
+use rc5::{Error, KeyLengthPolicy, RC5, RC6};
+
+const KEY: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+];
+
+#[test]
+fn strong_parameters() {
+    let rc5 = RC5::<32, 12, 16, 4, 8, 26, 4>::new(KEY);
+    assert_eq!(
+        rc5.encrypt([0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77]),
+        [0x2D, 0xDC, 0x14, 0x9B, 0xCF, 0x08, 0x8B, 0x9E]
+    );
+
+    let rc6 = RC6::<32, 20, 16, 4, 16, 44, 4>::new([0x00; 16]);
+    assert_eq!(
+        rc6.encrypt([0x00; 16]),
+        [
+            0x8F, 0xC3, 0xA5, 0x36, 0x56, 0xB1, 0xF7, 0x78, 0xC1, 0x29, 0xDF, 0x4E, 0x98, 0x48,
+            0xA4, 0x1E,
+        ]
+    );
+}
+
+#[test]
+fn new_from_slice_weak_key() {
+    for policy in [
+        KeyLengthPolicy::Exact,
+        KeyLengthPolicy::Truncate,
+        KeyLengthPolicy::Compress,
+    ] {
+        assert!(matches!(
+            RC5::<32, 12, 16, 4, 8, 26, 4>::new_from_slice(&KEY[..15], policy),
+            Err(Error::WeakKey)
+        ));
+    }
+
+    assert!(RC5::<32, 12, 16, 4, 8, 26, 4>::new_from_slice(&KEY, KeyLengthPolicy::Exact).is_ok());
+    assert!(
+        RC5::<32, 12, 16, 4, 8, 26, 4>::new_from_slice(&[0x00; 20], KeyLengthPolicy::Compress)
+            .is_ok()
+    );
+}